@@ -1,7 +1,28 @@
 mod tui;
 
+use clap::{arg, command};
 use std::error::Error;
+use std::path::PathBuf;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    tui::run()
+    let matches = command!()
+        .arg(
+            arg!(--"disable-lint" <RULES> "Comma separated lint rules to skip, e.g. latest-tag,run-as-root")
+                .required(false),
+        )
+        .arg(
+            arg!(--"tls-snapshot" <PATH> "File or directory of `kubectl get secrets -o json` output for the tls view")
+                .required(false)
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(arg!(--"no-wraparound" "Stop j/k at the first and last row instead of wrapping around"))
+        .get_matches();
+    let disabled_lints = matches.get_one::<String>("disable-lint");
+    let tls_snapshot = matches.get_one::<PathBuf>("tls-snapshot");
+    let wraparound = !matches.get_flag("no-wraparound");
+    tui::run(
+        disabled_lints.map(String::as_str),
+        tls_snapshot.map(PathBuf::as_path),
+        wraparound,
+    )
 }
@@ -0,0 +1,30 @@
+use crate::tui::discovery::{ApiResource, Discovery, DiscoveryError};
+use crate::tui::table_app::TableApp;
+use itertools::Itertools;
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) table: TableApp<ApiResource>,
+    /// Why discovery failed, or which group versions it could not list.
+    pub(crate) error: Option<String>,
+}
+
+impl App {
+    pub fn new(discovery: &Result<Discovery, DiscoveryError>) -> Self {
+        let (resources, error) = match discovery {
+            Ok(discovery) if discovery.errors.is_empty() => (discovery.resources.clone(), None),
+            Ok(discovery) => (
+                discovery.resources.clone(),
+                Some(format!(
+                    "unable to retrieve the complete list of server APIs: {}",
+                    discovery.errors.iter().join(", ")
+                )),
+            ),
+            Err(error) => (Vec::new(), Some(error.to_string())),
+        };
+        Self {
+            table: TableApp::new(resources, 1),
+            error,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::tui::api_resource_app::app::App;
+use crate::tui::prompt::Prompt;
+use crate::tui::table_ui::{render_footer, TuiTableState};
+use ratatui::prelude::*;
+
+pub fn ui(f: &mut Frame, app: &mut App, prompt: Option<&Prompt>) {
+    let rects = Layout::vertical([Constraint::Min(5), Constraint::Length(3)]).split(f.size());
+
+    app.table.set_colors();
+    app.table.render(f, rects[0]);
+
+    let kinds = app.table.all_items.len();
+    let status = match &app.error {
+        Some(error) if kinds == 0 => format!("api-resources: {error}"),
+        Some(error) => format!("api-resources | {kinds} kinds | {error}"),
+        None => format!("api-resources | {kinds} kinds"),
+    };
+    let status = app.table.status(Some(status));
+    render_footer(f, app.table.get_table_colors(), rects[1], prompt, status);
+}
@@ -0,0 +1,26 @@
+use crate::tui::certs::{scan, Cert, SecretSource};
+use crate::tui::table_app::TableApp;
+use chrono::Utc;
+
+/// Leaf certificates of the TLS Secrets, most urgent first.
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) table: TableApp<Cert>,
+    /// Where the Secrets were read from, or why they could not be.
+    pub(crate) origin: String,
+    pub(crate) error: Option<String>,
+}
+
+impl App {
+    pub fn new(source: &dyn SecretSource) -> Self {
+        let (data_vec, error) = match source.secrets() {
+            Ok(secrets) => (scan(&secrets, Utc::now()), None),
+            Err(error) => (Vec::new(), Some(error.to_string())),
+        };
+        Self {
+            table: TableApp::new(data_vec, 0),
+            origin: source.origin(),
+            error,
+        }
+    }
+}
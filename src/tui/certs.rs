@@ -0,0 +1,403 @@
+use std::fs;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::{DateTime, Datelike, Duration, Utc};
+use itertools::Itertools;
+use ratatui::style::Style;
+use serde_json::{json, Value};
+use thiserror::Error;
+use x509_parser::extensions::GeneralName;
+use x509_parser::pem::parse_x509_pem;
+
+use crate::tui::style::TableColors;
+use crate::tui::table_view::{Column, TableRow, Truncate};
+
+pub const TLS_SECRET_TYPE: &str = "kubernetes.io/tls";
+
+#[derive(Debug, Error)]
+pub enum CertError {
+    #[error("read {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("parse {path}: {source}")]
+    Json {
+        path: String,
+        source: serde_json::Error,
+    },
+    #[error("no tls.crt")]
+    MissingCert,
+    #[error("tls.crt is not base64: {0}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("tls.crt is not PEM: {0}")]
+    Pem(String),
+    #[error("tls.crt is not a certificate: {0}")]
+    X509(String),
+}
+
+/// Where Secrets come from: the fake backend, or `kubectl get secrets -o json`
+/// snapshots on disk.
+pub trait SecretSource {
+    fn secrets(&self) -> Result<Vec<Value>, CertError>;
+
+    /// Shown in the footer, e.g. the snapshot path.
+    fn origin(&self) -> String;
+}
+
+/// How soon a certificate needs attention.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Urgency {
+    Expired,
+    Critical,
+    Soon,
+    Fine,
+}
+
+impl Urgency {
+    /// Expired, within a week, within a month, or later.
+    pub const fn from_days(days_left: i64) -> Self {
+        match days_left {
+            i64::MIN..=-1 => Self::Expired,
+            0..=7 => Self::Critical,
+            8..=30 => Self::Soon,
+            _ => Self::Fine,
+        }
+    }
+}
+
+/// The leaf certificate of a TLS Secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cert {
+    pub(crate) secret: String,
+    pub(crate) subject: String,
+    pub(crate) sans: String,
+    pub(crate) issuer: String,
+    pub(crate) expires: String,
+    /// `None` when the Secret does not hold a readable certificate.
+    pub(crate) days_left: Option<i64>,
+}
+
+impl Cert {
+    pub(crate) const fn ref_array(&self) -> [&String; 5] {
+        [
+            &self.secret,
+            &self.subject,
+            &self.sans,
+            &self.issuer,
+            &self.expires,
+        ]
+    }
+
+    /// Unreadable certificates count as expired so they sort to the top.
+    pub fn urgency(&self) -> Urgency {
+        self.days_left.map_or(Urgency::Expired, Urgency::from_days)
+    }
+
+    /// Parse the first certificate of a PEM bundle, the leaf by convention.
+    pub fn parse(secret: String, pem: &[u8], now: DateTime<Utc>) -> Result<Self, CertError> {
+        let (_, pem) = parse_x509_pem(pem).map_err(|e| CertError::Pem(e.to_string()))?;
+        let cert = pem
+            .parse_x509()
+            .map_err(|e| CertError::X509(e.to_string()))?;
+        let sans = cert
+            .subject_alternative_name()
+            .map_err(|e| CertError::X509(e.to_string()))?
+            .map(|ext| {
+                ext.value
+                    .general_names
+                    .iter()
+                    .filter_map(|name| match name {
+                        GeneralName::DNSName(dns) => Some((*dns).to_string()),
+                        GeneralName::IPAddress(&[a, b, c, d]) => {
+                            Some(IpAddr::from([a, b, c, d]).to_string())
+                        }
+                        GeneralName::RFC822Name(email) => Some((*email).to_string()),
+                        _ => None,
+                    })
+                    .join(",")
+            })
+            .unwrap_or_default();
+        let not_after = DateTime::from_timestamp(cert.validity().not_after.timestamp(), 0)
+            .ok_or_else(|| CertError::X509("notAfter out of range".to_string()))?;
+        let days_left = (not_after - now).num_days() - i64::from(not_after < now);
+        let expires = match days_left {
+            i64::MIN..=-1 => format!("{} (expired {}d ago)", not_after.format("%F"), -days_left),
+            _ => format!("{} (in {days_left}d)", not_after.format("%F")),
+        };
+        Ok(Self {
+            secret,
+            subject: cert.subject().to_string(),
+            sans,
+            issuer: cert.issuer().to_string(),
+            expires,
+            days_left: Some(days_left),
+        })
+    }
+
+    /// A row for a Secret whose certificate could not be read; the error
+    /// takes the place of the subject.
+    fn unreadable(secret: String, error: &CertError) -> Self {
+        Self {
+            secret,
+            subject: error.to_string(),
+            sans: String::new(),
+            issuer: String::new(),
+            expires: "<invalid>".to_string(),
+            days_left: None,
+        }
+    }
+}
+
+impl TableRow for Cert {
+    const KIND: &'static str = "certificates";
+    const FIELDS: &'static [&'static str] = &["days_left"];
+    const COLUMNS: &'static [Column] = &[
+        Column::new("Secret").truncate(Truncate::Middle),
+        Column::new("Subject").priority(2),
+        Column::new("SANs").priority(3),
+        Column::new("Issuer").priority(1),
+        Column::new("Expires"),
+    ];
+
+    fn cells(&self) -> Vec<String> {
+        self.ref_array().into_iter().cloned().collect()
+    }
+
+    fn key(&self) -> String {
+        self.secret.clone()
+    }
+
+    fn field_values(&self) -> Vec<String> {
+        vec![self
+            .days_left
+            .map_or_else(String::new, |days| days.to_string())]
+    }
+
+    fn row_style(&self, colors: &TableColors, index: usize) -> Style {
+        colors.urgency_style(self.urgency(), index)
+    }
+}
+
+fn secret_cert(secret: &Value, now: DateTime<Utc>) -> Cert {
+    let name = format!(
+        "{}/{}",
+        secret["metadata"]["namespace"]
+            .as_str()
+            .unwrap_or("default"),
+        secret["metadata"]["name"].as_str().unwrap_or_default()
+    );
+    let parsed = secret["data"]["tls.crt"]
+        .as_str()
+        .ok_or(CertError::MissingCert)
+        .and_then(|data| Ok(BASE64.decode(data)?))
+        .and_then(|pem| Cert::parse(name.clone(), &pem, now));
+    parsed.unwrap_or_else(|error| Cert::unreadable(name, &error))
+}
+
+/// Every TLS Secret in `objects`, which may hold single Secrets or `List`s
+/// as printed by kubectl, most urgent first.
+pub fn scan(objects: &[Value], now: DateTime<Utc>) -> Vec<Cert> {
+    objects
+        .iter()
+        .flat_map(|object| match object["items"].as_array() {
+            Some(items) => items.iter().collect_vec(),
+            None => vec![object],
+        })
+        .filter(|secret| secret["type"] == TLS_SECRET_TYPE)
+        .map(|secret| secret_cert(secret, now))
+        .sorted_by(|a, b| {
+            (a.days_left.is_some(), a.days_left, &a.secret).cmp(&(
+                b.days_left.is_some(),
+                b.days_left,
+                &b.secret,
+            ))
+        })
+        .collect()
+}
+
+/// A JSON file, or every `.json` file in a directory.
+pub struct SnapshotSecrets {
+    pub(crate) path: PathBuf,
+}
+
+impl SnapshotSecrets {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn read(path: &Path) -> Result<Value, CertError> {
+        let text = fs::read_to_string(path).map_err(|source| CertError::Io {
+            path: path.display().to_string(),
+            source,
+        })?;
+        serde_json::from_str(&text).map_err(|source| CertError::Json {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+}
+
+impl SecretSource for SnapshotSecrets {
+    fn secrets(&self) -> Result<Vec<Value>, CertError> {
+        let io = |source| CertError::Io {
+            path: self.path.display().to_string(),
+            source,
+        };
+        if !self.path.is_dir() {
+            return Ok(vec![Self::read(&self.path)?]);
+        }
+        fs::read_dir(&self.path)
+            .map_err(io)?
+            .map(|entry| entry.map(|entry| entry.path()).map_err(io))
+            .filter_ok(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .map(|path| Self::read(&path?))
+            .collect()
+    }
+
+    fn origin(&self) -> String {
+        self.path.display().to_string()
+    }
+}
+
+/// TLS Secrets with freshly minted certificates expiring anywhere from a
+/// few days ago to a year out, plus one Secret holding garbage.
+pub struct FakeSecrets;
+
+fn tls_secret(name: &str, cert_pem: &str) -> Value {
+    json!({
+        "apiVersion": "v1",
+        "kind": "Secret",
+        "type": TLS_SECRET_TYPE,
+        "metadata": { "name": name, "namespace": "default" },
+        "data": { "tls.crt": BASE64.encode(cert_pem), "tls.key": "" },
+    })
+}
+
+impl SecretSource for FakeSecrets {
+    fn secrets(&self) -> Result<Vec<Value>, CertError> {
+        use rcgen::{CertificateParams, DnType, IsCa, Issuer, KeyPair};
+
+        const HOSTS: [(&str, i64); 8] = [
+            ("api", -3),
+            ("grafana", 2),
+            ("auth", 6),
+            ("shop", 21),
+            ("docs", 45),
+            ("www", 89),
+            ("registry", 200),
+            ("mail", 365),
+        ];
+
+        let minted = |e: rcgen::Error| CertError::X509(e.to_string());
+        #[allow(clippy::cast_possible_truncation)]
+        let ymd = |date: DateTime<Utc>| {
+            rcgen::date_time_ymd(date.year(), date.month() as u8, date.day() as u8)
+        };
+        let now = Utc::now();
+        let mut ca_params = CertificateParams::default();
+        ca_params
+            .distinguished_name
+            .push(DnType::CommonName, "fake-cluster-ca");
+        ca_params
+            .distinguished_name
+            .push(DnType::OrganizationName, "Acme");
+        ca_params.is_ca = IsCa::Ca(rcgen::BasicConstraints::Unconstrained);
+        let ca = Issuer::new(ca_params, KeyPair::generate().map_err(minted)?);
+
+        let mut secrets = HOSTS
+            .into_iter()
+            .map(|(host, days)| {
+                let domain = format!("{host}.acme.dev");
+                let mut params =
+                    CertificateParams::new(vec![domain.clone(), format!("www.{domain}")])
+                        .map_err(minted)?;
+                params.distinguished_name.push(DnType::CommonName, &domain);
+                params.not_before = ymd(now - Duration::days(90));
+                params.not_after = ymd(now + Duration::days(days + 1));
+                let key = KeyPair::generate().map_err(minted)?;
+                let cert = params.signed_by(&key, &ca).map_err(minted)?;
+                Ok(tls_secret(&format!("{host}-tls"), &cert.pem()))
+            })
+            .collect::<Result<Vec<_>, CertError>>()?;
+        secrets.push(tls_secret("legacy-tls", "not a certificate"));
+        Ok(secrets)
+    }
+
+    fn origin(&self) -> String {
+        "fake cluster".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::certs::{scan, Cert, CertError, FakeSecrets, SecretSource, Urgency};
+    use crate::tui::query::Query;
+    use chrono::Utc;
+    use serde_json::json;
+
+    #[test]
+    fn test_urgency() {
+        assert_eq!(Urgency::Expired, Urgency::from_days(-1));
+        assert_eq!(Urgency::Critical, Urgency::from_days(0));
+        assert_eq!(Urgency::Soon, Urgency::from_days(30));
+        assert_eq!(Urgency::Fine, Urgency::from_days(31));
+    }
+
+    #[test]
+    fn test_scan_fake_secrets() {
+        let mut secrets = FakeSecrets.secrets().unwrap();
+        secrets.push(json!({ "kind": "Secret", "type": "Opaque", "data": {} }));
+        let certs = scan(&[json!({ "kind": "List", "items": secrets })], Utc::now());
+
+        assert_eq!(9, certs.len());
+        assert_eq!("default/legacy-tls", certs[0].secret);
+        assert_eq!(None, certs[0].days_left);
+        assert_eq!(Urgency::Expired, certs[0].urgency());
+
+        let api = &certs[1];
+        assert_eq!("default/api-tls", api.secret);
+        assert_eq!(Some(-3), api.days_left);
+        assert!(api.expires.ends_with("(expired 3d ago)"));
+        assert_eq!("CN=api.acme.dev", api.subject);
+        assert_eq!("api.acme.dev,www.api.acme.dev", api.sans);
+        assert_eq!("CN=fake-cluster-ca, O=Acme", api.issuer);
+
+        let days = certs.iter().map(|c| c.days_left).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                None,
+                Some(-3),
+                Some(2),
+                Some(6),
+                Some(21),
+                Some(45),
+                Some(89),
+                Some(200),
+                Some(365)
+            ],
+            days
+        );
+        assert_eq!(Urgency::Critical, certs[2].urgency());
+        assert_eq!(Urgency::Soon, certs[4].urgency());
+
+        let due = Query::parse::<Cert>("days_left >= 0 && days_left <= 30").unwrap();
+        let due = certs.iter().filter(|cert| due.matches(*cert)).count();
+        assert_eq!(3, due);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parsed = Cert::parse("default/a".to_string(), b"nope", Utc::now());
+        assert!(matches!(parsed, Err(CertError::Pem(_))));
+
+        let certs = scan(
+            &[json!({ "type": "kubernetes.io/tls", "metadata": { "name": "a" }, "data": {} })],
+            Utc::now(),
+        );
+        assert_eq!("no tls.crt", certs[0].subject);
+        assert_eq!("<invalid>", certs[0].expires);
+    }
+}
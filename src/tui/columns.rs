@@ -0,0 +1,360 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use itertools::Itertools;
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::tui::style::{TableColors, PALETTES};
+use crate::tui::table_view::{Column, TableRow};
+
+#[derive(Debug, Error)]
+pub enum ColumnsError {
+    #[error("read {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("parse {path}: {source}")]
+    Json {
+        path: String,
+        source: serde_json::Error,
+    },
+}
+
+/// The order and visibility the user picked for the columns of one resource
+/// kind, by column title so it survives columns being added.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnLayout {
+    pub(crate) order: Vec<String>,
+    pub(crate) hidden: Vec<String>,
+}
+
+impl ColumnLayout {
+    /// Indexes of `columns` in display order: the saved order first, then
+    /// the columns it does not name.
+    pub fn arranged(&self, columns: &[Column]) -> Vec<usize> {
+        let named = self
+            .order
+            .iter()
+            .filter_map(|title| columns.iter().position(|column| column.title == title));
+        let mut arranged: Vec<usize> = Vec::new();
+        for i in named.chain(0..columns.len()) {
+            if !arranged.contains(&i) {
+                arranged.push(i);
+            }
+        }
+        arranged
+    }
+
+    /// Like [`ColumnLayout::arranged`] without the hidden columns. A layout
+    /// hiding every column, e.g. from a hand edited file, still shows the
+    /// first one.
+    pub fn shown(&self, columns: &[Column]) -> Vec<usize> {
+        let arranged = self.arranged(columns);
+        let shown = arranged
+            .iter()
+            .copied()
+            .filter(|&i| !self.hidden.iter().any(|title| title == columns[i].title))
+            .collect_vec();
+        if shown.is_empty() {
+            arranged.into_iter().take(1).collect()
+        } else {
+            shown
+        }
+    }
+
+    fn from_json(value: &Value) -> Self {
+        let titles = |key: &str| {
+            value[key]
+                .as_array()
+                .map(|titles| {
+                    titles
+                        .iter()
+                        .filter_map(|title| title.as_str().map(ToString::to_string))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        Self {
+            order: titles("order"),
+            hidden: titles("hidden"),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({ "order": self.order, "hidden": self.hidden })
+    }
+}
+
+/// Column layouts of every resource kind, saved as JSON so they carry over
+/// to the next session.
+#[derive(Debug, Default)]
+pub struct ColumnStore {
+    /// `None` keeps the layouts in memory only.
+    path: Option<PathBuf>,
+    layouts: BTreeMap<String, ColumnLayout>,
+}
+
+impl ColumnStore {
+    /// The layouts saved at `path`; a missing file holds none.
+    pub fn load(path: PathBuf) -> Result<Self, ColumnsError> {
+        let layouts = match fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str::<Value>(&text)
+                .map_err(|source| ColumnsError::Json {
+                    path: path.display().to_string(),
+                    source,
+                })?
+                .as_object()
+                .map(|kinds| {
+                    kinds
+                        .iter()
+                        .map(|(kind, layout)| (kind.clone(), ColumnLayout::from_json(layout)))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(source) => {
+                return Err(ColumnsError::Io {
+                    path: path.display().to_string(),
+                    source,
+                })
+            }
+        };
+        Ok(Self {
+            path: Some(path),
+            layouts,
+        })
+    }
+
+    pub fn layout(&self, kind: &str) -> ColumnLayout {
+        self.layouts.get(kind).cloned().unwrap_or_default()
+    }
+
+    /// Replace the layout of `kind` and write every layout back to disk.
+    pub fn set_layout(&mut self, kind: &str, layout: ColumnLayout) -> Result<(), ColumnsError> {
+        self.layouts.insert(kind.to_string(), layout);
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let io = |source| ColumnsError::Io {
+            path: path.display().to_string(),
+            source,
+        };
+        let json = self
+            .layouts
+            .iter()
+            .map(|(kind, layout)| (kind.clone(), layout.to_json()))
+            .collect::<serde_json::Map<_, _>>();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io)?;
+        }
+        let text = serde_json::to_string_pretty(&json).map_err(|source| ColumnsError::Json {
+            path: path.display().to_string(),
+            source,
+        })?;
+        fs::write(path, text).map_err(io)
+    }
+}
+
+/// `columns.json` under `$XDG_CONFIG_HOME` or `~/.config`.
+pub fn default_path() -> Option<PathBuf> {
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config.join(env!("CARGO_PKG_NAME")).join("columns.json"))
+}
+
+pub enum ChooserAction {
+    Continue,
+    Close,
+}
+
+/// Popup listing every column of a view, where columns are shown or hidden
+/// and moved; each change applies at once and is saved.
+#[derive(Clone, Debug)]
+pub struct ColumnChooser {
+    pub(crate) kind: &'static str,
+    columns: &'static [Column],
+    /// Column indexes in display order, and whether each is shown.
+    pub(crate) items: Vec<(usize, bool)>,
+    pub(crate) selected: usize,
+    pub(crate) error: Option<String>,
+    pub(crate) colors: TableColors,
+}
+
+impl ColumnChooser {
+    /// The columns of `T` as `layout` arranges them.
+    pub fn open<T: TableRow>(layout: &ColumnLayout, color_index: usize) -> Self {
+        let shown = layout.shown(T::COLUMNS);
+        Self {
+            kind: T::KIND,
+            columns: T::COLUMNS,
+            items: layout
+                .arranged(T::COLUMNS)
+                .into_iter()
+                .map(|i| (i, shown.contains(&i)))
+                .collect(),
+            selected: 0,
+            error: None,
+            colors: TableColors::new(&PALETTES[color_index]),
+        }
+    }
+
+    pub fn title(&self, item: usize) -> &'static str {
+        self.columns[self.items[item].0].title
+    }
+
+    /// Apply `key`, saving changed layouts to `store`.
+    pub fn handle_key(&mut self, key: KeyEvent, store: &mut ColumnStore) -> ChooserAction {
+        let last = self.items.len().saturating_sub(1);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q' | 'o') => {
+                return ChooserAction::Close
+            }
+            KeyCode::Up if shift => self.move_selected(false, store),
+            KeyCode::Down if shift => self.move_selected(true, store),
+            KeyCode::Char('K') => self.move_selected(false, store),
+            KeyCode::Char('J') => self.move_selected(true, store),
+            KeyCode::Char('k') | KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('j') | KeyCode::Down => self.selected = (self.selected + 1).min(last),
+            KeyCode::Char(' ') => self.toggle_selected(store),
+            _ => {}
+        }
+        ChooserAction::Continue
+    }
+
+    /// Show or hide the selected column; the last shown column stays.
+    fn toggle_selected(&mut self, store: &mut ColumnStore) {
+        let shown = self.items.iter().filter(|(_, shown)| *shown).count();
+        let Some((_, selected)) = self.items.get_mut(self.selected) else {
+            return;
+        };
+        if *selected && shown == 1 {
+            self.error = Some("at least one column stays shown".to_string());
+            return;
+        }
+        *selected = !*selected;
+        self.save(store);
+    }
+
+    fn move_selected(&mut self, down: bool, store: &mut ColumnStore) {
+        let target = if down {
+            self.selected + 1
+        } else {
+            self.selected.wrapping_sub(1)
+        };
+        if target >= self.items.len() {
+            return;
+        }
+        self.items.swap(self.selected, target);
+        self.selected = target;
+        self.save(store);
+    }
+
+    fn save(&mut self, store: &mut ColumnStore) {
+        let layout = ColumnLayout {
+            order: (0..self.items.len())
+                .map(|item| self.title(item).to_string())
+                .collect(),
+            hidden: (0..self.items.len())
+                .filter(|&item| !self.items[item].1)
+                .map(|item| self.title(item).to_string())
+                .collect(),
+        };
+        self.error = store
+            .set_layout(self.kind, layout)
+            .err()
+            .map(|err| err.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::columns::{ColumnChooser, ColumnLayout, ColumnStore};
+    use crate::tui::data::Pod;
+    use crate::tui::table_view::{Column, TableRow};
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    const COLUMNS: [Column; 4] = [
+        Column::new("Name"),
+        Column::new("Description"),
+        Column::new("Age"),
+        Column::new("Restarts"),
+    ];
+
+    #[test]
+    fn test_layout_order() {
+        let layout = ColumnLayout {
+            order: vec![
+                "Restarts".to_string(),
+                "Gone".to_string(),
+                "Name".to_string(),
+            ],
+            hidden: vec!["Description".to_string()],
+        };
+        assert_eq!(vec![3, 0, 1, 2], layout.arranged(&COLUMNS));
+        assert_eq!(vec![3, 0, 2], layout.shown(&COLUMNS));
+        assert_eq!(vec![0, 1, 2, 3], ColumnLayout::default().shown(&COLUMNS));
+
+        let hide_all = ColumnLayout {
+            order: vec!["Age".to_string()],
+            hidden: COLUMNS
+                .iter()
+                .map(|column| column.title.to_string())
+                .collect(),
+        };
+        assert_eq!(vec![2], hide_all.shown(&COLUMNS));
+    }
+
+    #[test]
+    fn test_store_round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("columns-test-{}", std::process::id()))
+            .join("columns.json");
+        let layout = ColumnLayout {
+            order: vec!["R".to_string(), "Podname".to_string()],
+            hidden: vec!["Description".to_string()],
+        };
+        let mut store = ColumnStore::load(path.clone()).unwrap();
+        assert_eq!(ColumnLayout::default(), store.layout("pods"));
+        store.set_layout("pods", layout.clone()).unwrap();
+
+        let reloaded = ColumnStore::load(path.clone()).unwrap();
+        assert_eq!(layout, reloaded.layout("pods"));
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_chooser_keys() {
+        let mut store = ColumnStore::default();
+        let mut chooser = ColumnChooser::open::<Pod>(&store.layout(Pod::KIND), 0);
+        let mut press = |chooser: &mut ColumnChooser, code| {
+            chooser.handle_key(KeyEvent::from(code), &mut store);
+        };
+        press(&mut chooser, KeyCode::Char('j'));
+        press(&mut chooser, KeyCode::Char(' '));
+        assert_eq!("Description", chooser.title(1));
+        assert!(!chooser.items[1].1);
+
+        for _ in 0..4 {
+            press(&mut chooser, KeyCode::Char('j'));
+        }
+        assert_eq!("R", chooser.title(5));
+        for _ in 0..5 {
+            press(&mut chooser, KeyCode::Char('K'));
+        }
+        assert_eq!("R", chooser.title(0));
+        assert_eq!(0, chooser.selected);
+
+        let pod_columns = Pod::COLUMNS;
+        let layout = store.layout(Pod::KIND);
+        assert_eq!(
+            vec![5, 0, 2, 3, 4, 6, 7, 8, 9, 10],
+            layout.shown(pod_columns)
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use crate::tui::data::{generate_config_recs, ConfigObject};
+use crate::tui::table_app::TableApp;
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) table: TableApp<ConfigObject>,
+    pub(crate) secrets: bool,
+}
+
+impl App {
+    /// The Secrets when `secrets` is set, otherwise the ConfigMaps.
+    pub fn new(secrets: bool) -> Self {
+        let data_vec = generate_config_recs(secrets);
+        Self {
+            table: TableApp::new(data_vec, if secrets { 0 } else { 2 }),
+            secrets,
+        }
+    }
+}
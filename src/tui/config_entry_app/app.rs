@@ -0,0 +1,72 @@
+use crate::tui::data::{ConfigEntry, ConfigObject};
+use crate::tui::table_app::TableApp;
+use crate::tui::table_ui::TuiTableState;
+use crate::tui::table_view::TableRow;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) table: TableApp<ConfigEntry>,
+    pub(crate) object: String,
+    pub(crate) secret: bool,
+    pub(crate) audit: Vec<String>,
+}
+
+impl App {
+    pub fn new(object: &ConfigObject) -> Self {
+        Self {
+            table: TableApp::new(object.entries(), if object.secret { 0 } else { 2 }),
+            object: format!(
+                "{}/{}",
+                if object.secret { "secret" } else { "configmap" },
+                object.name
+            ),
+            secret: object.secret,
+            audit: Vec::new(),
+        }
+    }
+
+    /// Reveal or re-mask the value of the selected secret key. Every reveal is
+    /// recorded in the audit shown in the status bar.
+    pub fn toggle_reveal(&mut self) {
+        if !self.secret {
+            return;
+        }
+        let Some(key) = self.table.selected_item().map(TableRow::key) else {
+            return;
+        };
+        let audit = &mut self.audit;
+        self.table.update_rows(|entry| {
+            if entry.key() != key {
+                return;
+            }
+            entry.set_masked(!entry.masked);
+            if !entry.masked {
+                audit.push(format!(
+                    "{} at {}",
+                    entry.key,
+                    clock_time(SystemTime::now())
+                ));
+            }
+        });
+    }
+
+    pub fn audit_status(&self) -> Option<String> {
+        self.audit
+            .last()
+            .map(|last| format!("{} reveal(s), last {last}", self.audit.len()))
+    }
+}
+
+/// `HH:MM:SSZ` wall clock time in UTC.
+fn clock_time(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() % 86_400);
+    format!(
+        "{:02}:{:02}:{:02}Z",
+        secs / 3_600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
@@ -0,0 +1,19 @@
+use crate::tui::config_entry_app::app::App;
+use crate::tui::prompt::Prompt;
+use crate::tui::table_ui::{render_footer, TuiTableState};
+use ratatui::prelude::*;
+
+pub fn ui(f: &mut Frame, app: &mut App, prompt: Option<&Prompt>) {
+    let rects = Layout::vertical([Constraint::Min(5), Constraint::Length(3)]).split(f.size());
+
+    app.table.set_colors();
+    app.table.render(f, rects[0]);
+
+    let status = match app.audit_status() {
+        Some(audit) => format!("{} | (r) reveal | {audit}", app.object),
+        None if app.secret => format!("{} | (r) reveal", app.object),
+        None => app.object.clone(),
+    };
+    let status = app.table.status(Some(status));
+    render_footer(f, app.table.get_table_colors(), rects[1], prompt, status);
+}
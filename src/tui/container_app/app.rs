@@ -1,64 +1,46 @@
-use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
-use ratatui::widgets::{ScrollbarState, TableState};
-use crate::tui::data::{Container, container_constraint_len_calculator, generate_container_recs};
-use crate::tui::table_ui::TuiTableState;
+use crate::tui::data::Container;
+use crate::tui::lint::LintConfig;
+use crate::tui::metrics::MetricsSource;
+use crate::tui::table_app::TableApp;
+
+/// The columns that change with every sample.
+const METRICS_COLUMNS: [usize; 2] = [Container::CPU, Container::MEMORY];
 
 #[derive(Clone, Debug)]
 pub struct App {
-    pub(crate) state: TableState,
-    pub(crate) items: Vec<Container>,
-    pub(crate) longest_item_lens: (u16, u16),
-    pub(crate) scroll_state: ScrollbarState,
-    pub(crate) colors: TableColors,
-    color_index: usize,
-}
-
-impl TuiTableState for App {
-    type Item = Container;
-
-    fn get_items(&self) -> &[Self::Item] {
-        &self.items
-    }
-
-    fn get_state(&mut self) -> &mut TableState {
-        &mut self.state
-    }
-
-    fn get_scroll_state(&self) -> &ScrollbarState {
-        &self.scroll_state
-    }
-
-    fn set_scroll_state(&mut self, scroll_state: ScrollbarState) {
-        self.scroll_state = scroll_state;
-    }
-
-    fn get_table_colors(&self) -> &TableColors {
-        &self.colors
-    }
-
-    fn set_table_colors(&mut self, colors: TableColors) {
-        self.colors = colors;
-    }
-
-    fn get_color_index(&self) -> usize {
-        self.color_index
-    }
-
-    fn set_color_index(&mut self, color_index: usize) {
-        self.color_index = color_index;
-    }
+    pub(crate) table: TableApp<Container>,
+    pub(crate) scope: Option<String>,
+    pub(crate) show_lint: bool,
+    /// The rules the Lint column counts.
+    pub(crate) lint: LintConfig,
 }
 
 impl App {
-    pub fn new() -> Self {
-        let data_vec = generate_container_recs();
+    /// The containers of a single pod, linted with `lint`.
+    pub fn for_pod(pod: &str, containers: Vec<Container>, lint: &LintConfig) -> Self {
+        Self::with_containers(containers, Some(format!("pod: {pod}")), lint)
+    }
+
+    fn with_containers(
+        mut data_vec: Vec<Container>,
+        scope: Option<String>,
+        lint: &LintConfig,
+    ) -> Self {
+        for container in &mut data_vec {
+            container.lint(lint);
+        }
         Self {
-            state: TableState::default().with_selected(0),
-            longest_item_lens: container_constraint_len_calculator(&data_vec),
-            scroll_state: ScrollbarState::new((data_vec.len() - 1) * ITEM_HEIGHT),
-            colors: TableColors::new(&PALETTES[0]),
-            color_index: 2,
-            items: data_vec,
+            table: TableApp::new(data_vec, 2),
+            scope,
+            show_lint: false,
+            lint: lint.clone(),
         }
     }
-}
\ No newline at end of file
+
+    /// Take the next usage sample of every container, keeping the selection.
+    pub fn sample_metrics(&mut self, source: &mut impl MetricsSource) {
+        self.table
+            .update_rows(|container| container.record_metrics(source));
+        self.table.resort_after(&METRICS_COLUMNS);
+    }
+}
@@ -0,0 +1,223 @@
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CronError {
+    #[error("expected 5 fields, found {0}")]
+    FieldCount(usize),
+    #[error("invalid {field} field '{value}'")]
+    InvalidField { field: &'static str, value: String },
+    #[error("unknown macro '{0}'")]
+    UnknownMacro(String),
+}
+
+/// A CronJob schedule in the standard five field format (`*/15 * * * *`) or
+/// one of the `@hourly`, `@daily`, ... macros.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: Vec<u32>,
+    hours: Vec<u32>,
+    days_of_month: Vec<u32>,
+    months: Vec<u32>,
+    days_of_week: Vec<u32>,
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+const FIELDS: [(&str, u32, u32); 5] = [
+    ("minute", 0, 59),
+    ("hour", 0, 23),
+    ("day of month", 1, 31),
+    ("month", 1, 12),
+    ("day of week", 0, 7),
+];
+
+impl CronSchedule {
+    pub fn parse(schedule: &str) -> Result<Self, CronError> {
+        let schedule = match schedule.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other if other.starts_with('@') => {
+                return Err(CronError::UnknownMacro(other.to_string()))
+            }
+            other => other,
+        };
+        let fields = schedule.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != FIELDS.len() {
+            return Err(CronError::FieldCount(fields.len()));
+        }
+        let values = fields
+            .iter()
+            .zip(FIELDS)
+            .map(|(value, (field, min, max))| parse_field(value, field, min, max))
+            .collect::<Result<Vec<_>, _>>()?;
+        let [minutes, hours, days_of_month, months, days_of_week] =
+            <[Vec<u32>; 5]>::try_from(values).map_err(|v| CronError::FieldCount(v.len()))?;
+        // Both 0 and 7 mean Sunday.
+        let mut days_of_week = days_of_week.into_iter().map(|d| d % 7).collect::<Vec<_>>();
+        days_of_week.sort_unstable();
+        days_of_week.dedup();
+        Ok(Self {
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week,
+            any_day_of_month: fields[2] == "*",
+            any_day_of_week: fields[4] == "*",
+        })
+    }
+
+    /// Like cron, a day matches when either day field matches if both are
+    /// restricted, otherwise the restricted one decides.
+    fn matches_day(&self, time: &DateTime<Utc>) -> bool {
+        let dom = self.days_of_month.contains(&time.day());
+        let dow = self
+            .days_of_week
+            .contains(&time.weekday().num_days_from_sunday());
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (false, false) => dom || dow,
+            (false, true) => dom,
+            (true, false) => dow,
+            (true, true) => true,
+        }
+    }
+
+    /// The first scheduled minute strictly after `after`, searching up to five
+    /// years ahead so impossible dates such as Feb 30 end the search.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = Utc
+            .with_ymd_and_hms(after.year(), after.month(), after.day(), 0, 0, 0)
+            .single()?;
+        (0..5 * 366)
+            .map(|day| start + Duration::days(day))
+            .filter(|day| self.months.contains(&day.month()) && self.matches_day(day))
+            .flat_map(|day| {
+                self.hours.iter().flat_map(move |hour| {
+                    self.minutes.iter().map(move |minute| {
+                        day + Duration::hours(i64::from(*hour))
+                            + Duration::minutes(i64::from(*minute))
+                    })
+                })
+            })
+            .find(|time| *time > after)
+    }
+}
+
+fn parse_field(
+    value: &str,
+    field: &'static str,
+    min: u32,
+    max: u32,
+) -> Result<Vec<u32>, CronError> {
+    let invalid = || CronError::InvalidField {
+        field,
+        value: value.to_string(),
+    };
+    let mut values = Vec::new();
+    for part in value.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().map_err(|_| invalid())?),
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (
+                    start.parse().map_err(|_| invalid())?,
+                    end.parse().map_err(|_| invalid())?,
+                ),
+                None => {
+                    let start = range.parse().map_err(|_| invalid())?;
+                    (start, if part.contains('/') { max } else { start })
+                }
+            },
+        };
+        if step == 0 || start < min || end > max || start > end {
+            return Err(invalid());
+        }
+        values.extend((start..=end).step_by(step as usize));
+    }
+    values.sort_unstable();
+    values.dedup();
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::cron::{CronError, CronSchedule};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_next_after() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 15, 10, 7, 30).unwrap();
+
+        let every_quarter = CronSchedule::parse("*/15 * * * *").unwrap();
+        assert_eq!(
+            Utc.with_ymd_and_hms(2024, 3, 15, 10, 15, 0).unwrap(),
+            every_quarter.next_after(now).unwrap()
+        );
+
+        let nightly = CronSchedule::parse("@daily").unwrap();
+        assert_eq!(
+            Utc.with_ymd_and_hms(2024, 3, 16, 0, 0, 0).unwrap(),
+            nightly.next_after(now).unwrap()
+        );
+
+        // 2024-03-15 is a Friday, the next Monday is the 18th.
+        let weekdays = CronSchedule::parse("30 9 * * 1-5").unwrap();
+        assert_eq!(
+            Utc.with_ymd_and_hms(2024, 3, 18, 9, 30, 0).unwrap(),
+            weekdays.next_after(now).unwrap()
+        );
+
+        let leap_day = CronSchedule::parse("0 12 29 2 *").unwrap();
+        assert_eq!(
+            Utc.with_ymd_and_hms(2028, 2, 29, 12, 0, 0).unwrap(),
+            leap_day.next_after(now).unwrap()
+        );
+
+        let never = CronSchedule::parse("0 0 30 2 *").unwrap();
+        assert_eq!(None, never.next_after(now));
+    }
+
+    #[test]
+    fn test_day_of_month_or_day_of_week() {
+        // The 1st of the month or any Sunday.
+        let schedule = CronSchedule::parse("0 0 1 * 7").unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 3, 15, 10, 7, 30).unwrap();
+        assert_eq!(
+            Utc.with_ymd_and_hms(2024, 3, 17, 0, 0, 0).unwrap(),
+            schedule.next_after(now).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(CronError::FieldCount(4)),
+            CronSchedule::parse("* * * *")
+        );
+        assert_eq!(
+            Err(CronError::InvalidField {
+                field: "hour",
+                value: "24".to_string()
+            }),
+            CronSchedule::parse("0 24 * * *")
+        );
+        assert_eq!(
+            Err(CronError::InvalidField {
+                field: "minute",
+                value: "*/0".to_string()
+            }),
+            CronSchedule::parse("*/0 * * * *")
+        );
+        assert_eq!(
+            Err(CronError::UnknownMacro("@often".to_string())),
+            CronSchedule::parse("@often")
+        );
+    }
+}
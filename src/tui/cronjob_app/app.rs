@@ -0,0 +1,33 @@
+use crate::tui::data::{generate_cronjob_recs, CronJob};
+use crate::tui::table_app::TableApp;
+use crate::tui::table_ui::TuiTableState;
+use crate::tui::table_view::TableRow;
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) table: TableApp<CronJob>,
+    pub(crate) message: Option<String>,
+}
+
+impl App {
+    pub fn new() -> Self {
+        Self {
+            table: TableApp::new(generate_cronjob_recs(), 3),
+            message: None,
+        }
+    }
+
+    /// Start a Job from the selected CronJob now, outside of its schedule.
+    pub fn trigger_selected(&mut self) {
+        let Some(key) = self.table.selected_item().map(TableRow::key) else {
+            return;
+        };
+        let mut triggered = None;
+        self.table.update_rows(|cronjob| {
+            if cronjob.key() == key {
+                triggered = Some(cronjob.trigger().name.clone());
+            }
+        });
+        self.message = triggered.map(|job| format!("triggered job/{job}"));
+    }
+}
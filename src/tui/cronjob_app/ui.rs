@@ -0,0 +1,18 @@
+use crate::tui::cronjob_app::app::App;
+use crate::tui::prompt::Prompt;
+use crate::tui::table_ui::{render_footer, TuiTableState};
+use ratatui::prelude::*;
+
+pub fn ui(f: &mut Frame, app: &mut App, prompt: Option<&Prompt>) {
+    let rects = Layout::vertical([Constraint::Min(5), Constraint::Length(3)]).split(f.size());
+
+    app.table.set_colors();
+    app.table.render(f, rects[0]);
+
+    let status = app.message.as_ref().map_or_else(
+        || "cronjobs | (t) trigger now".to_string(),
+        |message| format!("cronjobs | (t) trigger now | {message}"),
+    );
+    let status = app.table.status(Some(status));
+    render_footer(f, app.table.get_table_colors(), rects[1], prompt, status);
+}
@@ -0,0 +1,765 @@
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::tui::data::format_age;
+use crate::tui::table_view::{Column, TableRow};
+
+const CRD_PATH: &str = "/apis/apiextensions.k8s.io/v1/customresourcedefinitions";
+
+#[derive(Debug, Error)]
+pub enum DiscoveryError {
+    #[error("GET {0}: not found")]
+    NotFound(String),
+    #[error("GET {path}: {source}")]
+    Json {
+        path: String,
+        source: serde_json::Error,
+    },
+    #[error("GET {path}: missing field '{field}'")]
+    MissingField { path: String, field: &'static str },
+}
+
+/// Where discovery documents come from: the API server, or the fake backend.
+pub trait DiscoveryEndpoint {
+    fn get(&self, path: &str) -> Result<String, DiscoveryError>;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrinterColumn {
+    pub(crate) name: String,
+    pub(crate) json_path: String,
+    pub(crate) kind: String,
+}
+
+impl PrinterColumn {
+    fn new(name: &str, json_path: &str, kind: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            json_path: json_path.to_string(),
+            kind: kind.to_string(),
+        }
+    }
+
+    /// The cell for `object`, with `date` columns shown as an age.
+    pub fn cell(&self, object: &Value, now: DateTime<Utc>) -> String {
+        match json_path(object, &self.json_path) {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(s)) if self.kind == "date" => DateTime::parse_from_rfc3339(s)
+                .map(|ts| {
+                    format_age(
+                        u64::try_from((now - ts.with_timezone(&Utc)).num_seconds()).unwrap_or(0),
+                    )
+                })
+                .unwrap_or_else(|_| s.clone()),
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Array(values)) => values.iter().map(scalar).join(","),
+            Some(value) => scalar(value),
+        }
+    }
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// A listable resource kind found through discovery, with the extra columns
+/// its CustomResourceDefinition asks for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApiResource {
+    pub(crate) group: String,
+    pub(crate) version: String,
+    pub(crate) kind: String,
+    pub(crate) name: String,
+    pub(crate) singular_name: String,
+    pub(crate) short_names: Vec<String>,
+    pub(crate) namespaced: bool,
+    pub(crate) printer_columns: Vec<PrinterColumn>,
+}
+
+impl ApiResource {
+    pub fn api_version(&self) -> String {
+        if self.group.is_empty() {
+            self.version.clone()
+        } else {
+            format!("{}/{}", self.group, self.version)
+        }
+    }
+
+    /// Whether `name` refers to this resource the way kubectl resolves it:
+    /// plural, singular, short name, kind, optionally qualified with the group.
+    pub fn matches(&self, name: &str) -> bool {
+        let (name, group) = name.split_once('.').unwrap_or((name, ""));
+        let name = name.to_lowercase();
+        (group.is_empty() || group == self.group)
+            && (name == self.name
+                || name == self.singular_name
+                || name == self.kind.to_lowercase()
+                || self.short_names.contains(&name))
+    }
+
+    /// Name and namespace, then the CRD printer columns, with an age column
+    /// last unless the CRD already defines one.
+    pub fn columns(&self) -> Vec<PrinterColumn> {
+        let mut columns = vec![PrinterColumn::new("Name", ".metadata.name", "string")];
+        if self.namespaced {
+            columns.push(PrinterColumn::new(
+                "Namespace",
+                ".metadata.namespace",
+                "string",
+            ));
+        }
+        columns.extend(self.printer_columns.iter().cloned());
+        if !columns.iter().any(|c| c.name.eq_ignore_ascii_case("age")) {
+            columns.push(PrinterColumn::new(
+                "Age",
+                ".metadata.creationTimestamp",
+                "date",
+            ));
+        }
+        columns
+    }
+}
+
+/// A row of `kubectl api-resources`.
+impl TableRow for ApiResource {
+    const KIND: &'static str = "apiresources";
+    const COLUMNS: &'static [Column] = &[
+        Column::new("Name"),
+        Column::new("Shortnames").priority(2),
+        Column::new("APIVersion").priority(1),
+        Column::new("Namespaced").priority(3),
+        Column::new("Kind"),
+    ];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.short_names.join(","),
+            self.api_version(),
+            self.namespaced.to_string(),
+            self.kind.clone(),
+        ]
+    }
+
+    /// The plural qualified with the group, as two groups may share one.
+    fn key(&self) -> String {
+        if self.group.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}.{}", self.name, self.group)
+        }
+    }
+}
+
+fn get_json(endpoint: &impl DiscoveryEndpoint, path: &str) -> Result<Value, DiscoveryError> {
+    let body = endpoint.get(path)?;
+    serde_json::from_str(&body).map_err(|source| DiscoveryError::Json {
+        path: path.to_string(),
+        source,
+    })
+}
+
+fn field<'a>(
+    value: &'a Value,
+    path: &str,
+    field: &'static str,
+) -> Result<&'a Value, DiscoveryError> {
+    value
+        .get(field)
+        .ok_or_else(|| DiscoveryError::MissingField {
+            path: path.to_string(),
+            field,
+        })
+}
+
+fn resource_list(
+    endpoint: &impl DiscoveryEndpoint,
+    path: &str,
+) -> Result<Vec<ApiResource>, DiscoveryError> {
+    let list = get_json(endpoint, path)?;
+    let group_version = field(&list, path, "groupVersion")?
+        .as_str()
+        .unwrap_or_default();
+    let (group, version) = group_version.split_once('/').unwrap_or(("", group_version));
+    let resources = field(&list, path, "resources")?
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    Ok(resources
+        .iter()
+        .filter_map(|r| {
+            let name = r.get("name")?.as_str()?;
+            // Subresources such as `pods/log` are not listable on their own.
+            if name.contains('/') {
+                return None;
+            }
+            Some(ApiResource {
+                group: group.to_string(),
+                version: version.to_string(),
+                kind: r.get("kind")?.as_str()?.to_string(),
+                name: name.to_string(),
+                singular_name: r
+                    .get("singularName")
+                    .and_then(Value::as_str)
+                    .filter(|s| !s.is_empty())
+                    .map_or_else(
+                        || name.trim_end_matches('s').to_string(),
+                        ToString::to_string,
+                    ),
+                short_names: r
+                    .get("shortNames")
+                    .and_then(Value::as_array)
+                    .map(|names| {
+                        names
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .map(ToString::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                namespaced: r
+                    .get("namespaced")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false),
+                printer_columns: Vec::new(),
+            })
+        })
+        .collect_vec())
+}
+
+/// What discovery found, next to the group versions that could not be
+/// listed.
+#[derive(Debug, Default)]
+pub struct Discovery {
+    pub(crate) resources: Vec<ApiResource>,
+    pub(crate) errors: Vec<DiscoveryError>,
+}
+
+/// Walk `/api` and `/apis` like `kubectl api-resources`, then attach the
+/// `additionalPrinterColumns` of every CustomResourceDefinition. A group
+/// version that fails to list is recorded and skipped, as kubectl does.
+pub fn discover(endpoint: &impl DiscoveryEndpoint) -> Result<Discovery, DiscoveryError> {
+    let mut resources = Vec::new();
+    let mut errors = Vec::new();
+    let mut list = |path: String| match resource_list(endpoint, &path) {
+        Ok(found) => resources.extend(found),
+        Err(err) => errors.push(err),
+    };
+
+    let core = get_json(endpoint, "/api")?;
+    for version in field(&core, "/api", "versions")?
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        if let Some(version) = version.as_str() {
+            list(format!("/api/{version}"));
+        }
+    }
+
+    let groups = get_json(endpoint, "/apis")?;
+    for group in field(&groups, "/apis", "groups")?
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        let preferred = group
+            .pointer("/preferredVersion/groupVersion")
+            .and_then(Value::as_str);
+        if let Some(group_version) = preferred {
+            list(format!("/apis/{group_version}"));
+        }
+    }
+
+    let crds = match get_json(endpoint, CRD_PATH) {
+        Ok(crds) => crds,
+        Err(DiscoveryError::NotFound(_)) => json!({ "items": [] }),
+        Err(err) => return Err(err),
+    };
+    for crd in field(&crds, CRD_PATH, "items")?
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        let group = crd.pointer("/spec/group").and_then(Value::as_str);
+        let plural = crd.pointer("/spec/names/plural").and_then(Value::as_str);
+        let versions = crd.pointer("/spec/versions").and_then(Value::as_array);
+        let (Some(group), Some(plural), Some(versions)) = (group, plural, versions) else {
+            continue;
+        };
+        for resource in resources
+            .iter_mut()
+            .filter(|r| r.group == group && r.name == plural)
+        {
+            let columns = versions
+                .iter()
+                .find(|v| v.get("name").and_then(Value::as_str) == Some(&resource.version))
+                .and_then(|v| v.get("additionalPrinterColumns"))
+                .and_then(Value::as_array);
+            resource.printer_columns = columns
+                .into_iter()
+                .flatten()
+                // Priority > 0 columns only show with `-o wide`.
+                .filter(|c| c.get("priority").and_then(Value::as_i64).unwrap_or(0) == 0)
+                .filter_map(|c| {
+                    Some(PrinterColumn::new(
+                        c.get("name")?.as_str()?,
+                        c.get("jsonPath")?.as_str()?,
+                        c.get("type").and_then(Value::as_str).unwrap_or("string"),
+                    ))
+                })
+                .collect();
+        }
+    }
+
+    resources.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.group.cmp(&b.group)));
+    Ok(Discovery { resources, errors })
+}
+
+/// The subset of JSONPath used by printer columns: `.a.b`, `[0]` and
+/// `[?(@.type=="Ready")]` filters that pick the first match.
+pub fn json_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let mut current = value;
+    let mut rest = path.trim().trim_start_matches('{').trim_end_matches('}');
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            current = current.get(&after[..end])?;
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']')?;
+            let selector = &after[..end];
+            rest = &after[end + 1..];
+            current = match selector
+                .strip_prefix("?(@.")
+                .and_then(|s| s.strip_suffix(')'))
+            {
+                Some(filter) => {
+                    let (key, expected) = filter.split_once("==")?;
+                    let expected = expected.trim().trim_matches(|c| c == '"' || c == '\'');
+                    current.as_array()?.iter().find(|item| {
+                        item.get(key.trim()).map(scalar).as_deref() == Some(expected)
+                    })?
+                }
+                None => current.get(selector.parse::<usize>().ok()?)?,
+            };
+        } else {
+            return None;
+        }
+    }
+    Some(current)
+}
+
+/// Render `value` the way `kubectl get -o yaml` lays it out.
+pub fn to_yaml(value: &Value) -> String {
+    let mut out = String::new();
+    write_yaml(&mut out, value, 0);
+    out
+}
+
+fn write_yaml(out: &mut String, value: &Value, indent: usize) {
+    let pad = "  ".repeat(indent);
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                match value {
+                    Value::Object(m) if !m.is_empty() => {
+                        out.push_str(&format!("{pad}{key}:\n"));
+                        write_yaml(out, value, indent + 1);
+                    }
+                    Value::Array(a) if !a.is_empty() => {
+                        out.push_str(&format!("{pad}{key}:\n"));
+                        write_yaml(out, value, indent);
+                    }
+                    _ => out.push_str(&format!("{pad}{key}: {}\n", yaml_scalar(value))),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                let nested = match item {
+                    Value::Object(m) => !m.is_empty(),
+                    Value::Array(a) => !a.is_empty(),
+                    _ => false,
+                };
+                if nested {
+                    let mut nested = String::new();
+                    write_yaml(&mut nested, item, indent + 1);
+                    // The first key or item goes on the dash line.
+                    out.push_str(&format!("{pad}- {}", nested.trim_start()));
+                } else {
+                    out.push_str(&format!("{pad}- {}\n", yaml_scalar(item)));
+                }
+            }
+        }
+        _ => out.push_str(&format!("{pad}{}\n", yaml_scalar(value))),
+    }
+}
+
+fn yaml_scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Object(_) => "{}".to_string(),
+        Value::Array(_) => "[]".to_string(),
+        Value::String(s) => {
+            let plain = !s.is_empty()
+                && s.parse::<f64>().is_err()
+                && !matches!(
+                    s.to_lowercase().as_str(),
+                    "true" | "false" | "null" | "yes" | "no" | "on" | "off" | "~"
+                )
+                && !s.starts_with([
+                    '-', '?', ':', '[', '{', '!', '*', '&', '#', '|', '>', '@', '`', '"', '\'', ' ',
+                ])
+                && !s.contains(": ")
+                && !s.contains(" #")
+                && !s.contains('\n');
+            if plain {
+                s.clone()
+            } else {
+                Value::String(s.clone()).to_string()
+            }
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Discovery documents served by the fake backend: a slice of the core and
+/// apps groups plus two CRDs with printer columns.
+pub struct FakeDiscovery;
+
+impl DiscoveryEndpoint for FakeDiscovery {
+    fn get(&self, path: &str) -> Result<String, DiscoveryError> {
+        let body = match path {
+            "/api" => json!({ "versions": ["v1"] }),
+            "/api/v1" => json!({
+                "groupVersion": "v1",
+                "resources": [
+                    { "name": "pods", "singularName": "pod", "namespaced": true, "kind": "Pod", "shortNames": ["po"] },
+                    { "name": "pods/log", "singularName": "", "namespaced": true, "kind": "Pod" },
+                    { "name": "namespaces", "singularName": "namespace", "namespaced": false, "kind": "Namespace", "shortNames": ["ns"] },
+                    { "name": "persistentvolumeclaims", "singularName": "persistentvolumeclaim", "namespaced": true, "kind": "PersistentVolumeClaim", "shortNames": ["pvc"] },
+                    { "name": "serviceaccounts", "singularName": "serviceaccount", "namespaced": true, "kind": "ServiceAccount", "shortNames": ["sa"] }
+                ]
+            }),
+            "/apis" => json!({
+                "groups": [
+                    { "name": "apps", "preferredVersion": { "groupVersion": "apps/v1", "version": "v1" } },
+                    { "name": "cert-manager.io", "preferredVersion": { "groupVersion": "cert-manager.io/v1", "version": "v1" } },
+                    { "name": "stable.example.com", "preferredVersion": { "groupVersion": "stable.example.com/v1", "version": "v1" } }
+                ]
+            }),
+            "/apis/apps/v1" => json!({
+                "groupVersion": "apps/v1",
+                "resources": [
+                    { "name": "deployments", "singularName": "deployment", "namespaced": true, "kind": "Deployment", "shortNames": ["deploy"] },
+                    { "name": "statefulsets", "singularName": "statefulset", "namespaced": true, "kind": "StatefulSet", "shortNames": ["sts"] },
+                    { "name": "daemonsets", "singularName": "daemonset", "namespaced": true, "kind": "DaemonSet", "shortNames": ["ds"] }
+                ]
+            }),
+            "/apis/cert-manager.io/v1" => json!({
+                "groupVersion": "cert-manager.io/v1",
+                "resources": [
+                    { "name": "certificates", "singularName": "certificate", "namespaced": true, "kind": "Certificate", "shortNames": ["cert", "certs"] },
+                    { "name": "certificates/status", "singularName": "", "namespaced": true, "kind": "Certificate" }
+                ]
+            }),
+            "/apis/stable.example.com/v1" => json!({
+                "groupVersion": "stable.example.com/v1",
+                "resources": [
+                    { "name": "crontabs", "singularName": "crontab", "namespaced": true, "kind": "CronTab", "shortNames": ["ct"] }
+                ]
+            }),
+            CRD_PATH => json!({
+                "items": [
+                    {
+                        "metadata": { "name": "certificates.cert-manager.io" },
+                        "spec": {
+                            "group": "cert-manager.io",
+                            "names": { "plural": "certificates", "kind": "Certificate" },
+                            "versions": [{
+                                "name": "v1",
+                                "additionalPrinterColumns": [
+                                    { "name": "Ready", "type": "string", "jsonPath": ".status.conditions[?(@.type==\"Ready\")].status" },
+                                    { "name": "Secret", "type": "string", "jsonPath": ".spec.secretName" },
+                                    { "name": "Issuer", "type": "string", "jsonPath": ".spec.issuerRef.name", "priority": 1 },
+                                    { "name": "Age", "type": "date", "jsonPath": ".metadata.creationTimestamp" }
+                                ]
+                            }]
+                        }
+                    },
+                    {
+                        "metadata": { "name": "crontabs.stable.example.com" },
+                        "spec": {
+                            "group": "stable.example.com",
+                            "names": { "plural": "crontabs", "kind": "CronTab" },
+                            "versions": [{
+                                "name": "v1",
+                                "additionalPrinterColumns": [
+                                    { "name": "Spec", "type": "string", "jsonPath": ".spec.cronSpec" },
+                                    { "name": "Replicas", "type": "integer", "jsonPath": ".spec.replicas" }
+                                ]
+                            }]
+                        }
+                    }
+                ]
+            }),
+            _ => return Err(DiscoveryError::NotFound(path.to_string())),
+        };
+        Ok(body.to_string())
+    }
+}
+
+/// Objects of any discovered kind, with a plausible spec and status for the
+/// kinds the fake backend knows about.
+pub fn generate_resource_objects(resource: &ApiResource) -> Vec<Value> {
+    use fakeit::{generator, misc};
+
+    let now = Utc::now();
+    (0..8)
+        .map(|_| {
+            let name = generator::generate(format!("{}-??#?#", resource.singular_name));
+            let created = now - chrono::Duration::seconds(misc::random(60, 200 * 86_400));
+            let mut object = json!({
+                "apiVersion": resource.api_version(),
+                "kind": resource.kind,
+                "metadata": {
+                    "name": name,
+                    "creationTimestamp": created.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                    "labels": { "app": misc::random_data(&["web", "api", "worker"]) },
+                },
+            });
+            if resource.namespaced {
+                object["metadata"]["namespace"] = json!(misc::random_data(&["default", "prod", "staging"]));
+            }
+            match resource.kind.as_str() {
+                "Certificate" => {
+                    object["spec"] = json!({
+                        "secretName": format!("{name}-tls"),
+                        "dnsNames": [format!("{name}.example.com")],
+                        "issuerRef": { "name": "letsencrypt", "kind": "ClusterIssuer" },
+                    });
+                    object["status"] = json!({
+                        "conditions": [
+                            { "type": "Issuing", "status": "False" },
+                            { "type": "Ready", "status": misc::random_data(&["True", "True", "False"]) },
+                        ],
+                    });
+                }
+                "CronTab" => {
+                    object["spec"] = json!({
+                        "cronSpec": misc::random_data(&["* * * * */5", "0 0 * * *"]),
+                        "image": "my-awesome-cron-image",
+                        "replicas": misc::random(1, 3),
+                    });
+                }
+                _ => {}
+            }
+            object
+        })
+        .sorted_by_key(|o| o.pointer("/metadata/name").and_then(Value::as_str).map(ToString::to_string))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::{TimeZone, Utc};
+    use serde_json::json;
+
+    use crate::tui::discovery::{
+        discover, json_path, to_yaml, DiscoveryEndpoint, DiscoveryError, FakeDiscovery, CRD_PATH,
+    };
+
+    /// Serves canned discovery documents by path, like a mock API server.
+    struct MockEndpoint(HashMap<&'static str, String>);
+
+    impl DiscoveryEndpoint for MockEndpoint {
+        fn get(&self, path: &str) -> Result<String, DiscoveryError> {
+            self.0
+                .get(path)
+                .cloned()
+                .ok_or_else(|| DiscoveryError::NotFound(path.to_string()))
+        }
+    }
+
+    fn mock_endpoint() -> MockEndpoint {
+        MockEndpoint(HashMap::from([
+            ("/api", json!({ "versions": ["v1"] }).to_string()),
+            (
+                "/api/v1",
+                json!({
+                    "groupVersion": "v1",
+                    "resources": [
+                        { "name": "configmaps", "singularName": "configmap", "namespaced": true, "kind": "ConfigMap", "shortNames": ["cm"] },
+                        { "name": "nodes", "singularName": "node", "namespaced": false, "kind": "Node", "shortNames": ["no"] },
+                        { "name": "nodes/proxy", "namespaced": false, "kind": "NodeProxyOptions" }
+                    ]
+                })
+                .to_string(),
+            ),
+            (
+                "/apis",
+                json!({ "groups": [{ "name": "db.example.com", "preferredVersion": { "groupVersion": "db.example.com/v1beta1" } }] })
+                    .to_string(),
+            ),
+            (
+                "/apis/db.example.com/v1beta1",
+                json!({
+                    "groupVersion": "db.example.com/v1beta1",
+                    "resources": [{ "name": "databases", "singularName": "database", "namespaced": true, "kind": "Database", "shortNames": ["db"] }]
+                })
+                .to_string(),
+            ),
+            (
+                CRD_PATH,
+                json!({
+                    "items": [{
+                        "spec": {
+                            "group": "db.example.com",
+                            "names": { "plural": "databases" },
+                            "versions": [
+                                { "name": "v1alpha1", "additionalPrinterColumns": [{ "name": "Old", "jsonPath": ".spec.old" }] },
+                                { "name": "v1beta1", "additionalPrinterColumns": [
+                                    { "name": "Engine", "type": "string", "jsonPath": ".spec.engine" },
+                                    { "name": "Storage", "type": "string", "jsonPath": ".spec.storage", "priority": 1 }
+                                ] }
+                            ]
+                        }
+                    }]
+                })
+                .to_string(),
+            ),
+        ]))
+    }
+
+    #[test]
+    fn test_discover_against_mock_endpoint() {
+        let resources = discover(&mock_endpoint()).unwrap().resources;
+
+        assert_eq!(
+            vec!["configmaps", "databases", "nodes"],
+            resources
+                .iter()
+                .map(|r| r.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        let database = &resources[1];
+        assert_eq!("db.example.com/v1beta1", database.api_version());
+        assert!(database.matches("db"));
+        assert!(database.matches("Database"));
+        assert!(database.matches("databases.db.example.com"));
+        assert!(!database.matches("databases.other.io"));
+        assert_eq!(
+            vec!["Name", "Namespace", "Engine", "Age"],
+            database
+                .columns()
+                .iter()
+                .map(|c| c.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        let node = &resources[2];
+        assert_eq!(
+            vec!["Name", "Age"],
+            node.columns()
+                .iter()
+                .map(|c| c.name.as_str())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_discover_errors() {
+        let mut endpoint = mock_endpoint();
+        endpoint.0.remove(CRD_PATH);
+        assert!(discover(&endpoint).is_ok(), "CRDs are optional");
+
+        endpoint.0.remove("/apis/db.example.com/v1beta1");
+        let discovery = discover(&endpoint).unwrap();
+        assert_eq!(
+            vec!["configmaps", "nodes"],
+            discovery
+                .resources
+                .iter()
+                .map(|r| r.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["GET /apis/db.example.com/v1beta1: not found"],
+            discovery
+                .errors
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+
+        endpoint.0.insert("/apis", "{".to_string());
+        assert!(matches!(
+            discover(&endpoint),
+            Err(DiscoveryError::Json { .. })
+        ));
+    }
+
+    #[test]
+    fn test_fake_discovery_printer_columns() {
+        let resources = discover(&FakeDiscovery).unwrap().resources;
+        let certificate = resources.iter().find(|r| r.matches("cert")).unwrap();
+        let object = json!({
+            "metadata": { "name": "web", "namespace": "prod", "creationTimestamp": "2024-03-15T10:00:00Z" },
+            "spec": { "secretName": "web-tls", "issuerRef": { "name": "letsencrypt" } },
+            "status": { "conditions": [{ "type": "Issuing", "status": "False" }, { "type": "Ready", "status": "True" }] }
+        });
+        let now = Utc.with_ymd_and_hms(2024, 3, 17, 10, 0, 0).unwrap();
+
+        assert_eq!(
+            vec!["web", "prod", "True", "web-tls", "2d"],
+            certificate
+                .columns()
+                .iter()
+                .map(|c| c.cell(&object, now))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_json_path() {
+        let object = json!({ "spec": { "ports": [{ "port": 80 }, { "port": 443 }] } });
+
+        assert_eq!(Some(&json!(443)), json_path(&object, ".spec.ports[1].port"));
+        assert_eq!(
+            Some(&json!(80)),
+            json_path(&object, "{.spec.ports[?(@.port==80)].port}")
+        );
+        assert_eq!(None, json_path(&object, ".spec.missing"));
+        assert_eq!(None, json_path(&object, "spec"));
+    }
+
+    #[test]
+    fn test_to_yaml() {
+        let object = json!({
+            "kind": "CronTab",
+            "metadata": { "name": "nightly", "labels": {} },
+            "spec": {
+                "cronSpec": "0 0 * * *",
+                "replicas": 2,
+                "args": ["--verbose", "true"],
+                "matrix": [["a", "b"], [], [{ "x": 1 }]]
+            },
+            "status": { "conditions": [{ "type": "Ready", "status": "True" }] }
+        });
+
+        assert_eq!(
+            "kind: CronTab\n\
+             metadata:\n  labels: {}\n  name: nightly\n\
+             spec:\n  args:\n  - \"--verbose\"\n  - \"true\"\n  cronSpec: 0 0 * * *\n  matrix:\n  - - a\n    - b\n  - []\n  - - x: 1\n  replicas: 2\n\
+             status:\n  conditions:\n  - status: \"True\"\n    type: Ready\n",
+            to_yaml(&object)
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use crate::tui::data::{format_age, generate_event_recs, Event};
+use crate::tui::table_app::TableApp;
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) table: TableApp<Event>,
+    pub(crate) object: Option<String>,
+    pub(crate) scope: Option<String>,
+}
+
+impl App {
+    /// Cluster wide events, newest first.
+    pub fn new() -> Self {
+        Self::with_object(None)
+    }
+
+    /// Timeline of the events involving a single object, e.g. `Pod/name`.
+    pub fn for_object(object: String) -> Self {
+        Self::with_object(Some(object))
+    }
+
+    /// Warning events seen within the last `secs`, out of `events`.
+    pub fn warnings_within(events: &[Event], secs: u64) -> Self {
+        let warnings = events
+            .iter()
+            .filter(|event| event.is_warning_within(secs))
+            .cloned()
+            .collect();
+        let mut app = Self::with_events(warnings, None);
+        app.scope = Some(format!("warnings in the last {}", format_age(secs)));
+        app
+    }
+
+    fn with_object(object: Option<String>) -> Self {
+        Self::with_events(generate_event_recs(object.as_deref()), object)
+    }
+
+    fn with_events(data_vec: Vec<Event>, object: Option<String>) -> Self {
+        Self {
+            table: TableApp::new(data_vec, 3),
+            object,
+            scope: None,
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use std::ops::Range;
+
+use itertools::Itertools;
+
+use crate::tui::query::{Query, QueryError};
+use crate::tui::sort::{sort_rows, SortedTable};
+use crate::tui::table_view::TableRow;
+
+/// Score of every matched character.
+const MATCH_SCORE: i32 = 16;
+/// Extra score for a character right after the previous match.
+const CONSECUTIVE_BONUS: i32 = 12;
+/// Extra score for a character starting a word, e.g. the `w` of `api-web`.
+const BOUNDARY_BONUS: i32 = 10;
+/// Score lost for every character skipped between two matches.
+const GAP_PENALTY: i32 = 1;
+
+/// A fuzzy filter: a row is kept when the characters of the pattern appear
+/// in order in one of its cells, and ranked by how closely they do. Case is
+/// ignored unless the pattern has an upper case letter.
+#[derive(Clone, Debug)]
+pub struct Filter {
+    pub(crate) pattern: String,
+    chars: Vec<char>,
+    ignore_case: bool,
+}
+
+impl Filter {
+    /// `None` for an empty pattern.
+    pub fn new(pattern: &str) -> Option<Self> {
+        if pattern.is_empty() {
+            return None;
+        }
+        Some(Self {
+            pattern: pattern.to_string(),
+            chars: pattern.chars().collect(),
+            ignore_case: !pattern.chars().any(char::is_uppercase),
+        })
+    }
+
+    fn same(&self, a: char, b: char) -> bool {
+        a == b || (self.ignore_case && a.to_lowercase().eq(b.to_lowercase()))
+    }
+
+    /// Score and char indexes of the best match of the pattern in `text`,
+    /// trying each place the first character appears.
+    pub fn fuzzy_match(&self, text: &str) -> Option<(i32, Vec<usize>)> {
+        let text = text.chars().collect_vec();
+        let first = self.chars[0];
+        (0..text.len())
+            .filter(|&start| self.same(text[start], first))
+            .filter_map(|start| self.match_from(&text, start))
+            .max_by_key(|(score, positions)| (*score, std::cmp::Reverse(positions[0])))
+    }
+
+    fn match_from(&self, text: &[char], start: usize) -> Option<(i32, Vec<usize>)> {
+        let mut positions = Vec::with_capacity(self.chars.len());
+        let mut next = start;
+        for &c in &self.chars {
+            let found = (next..text.len()).find(|&i| self.same(text[i], c))?;
+            positions.push(found);
+            next = found + 1;
+        }
+        let score = positions
+            .iter()
+            .enumerate()
+            .map(|(n, &i)| {
+                let boundary = i == 0 || !text[i - 1].is_alphanumeric();
+                let bonus = match n.checked_sub(1).map(|previous| positions[previous]) {
+                    Some(previous) if previous + 1 == i => CONSECUTIVE_BONUS,
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                    Some(previous) => -GAP_PENALTY * (i - previous - 1) as i32,
+                    None => 0,
+                };
+                MATCH_SCORE + bonus + if boundary { BOUNDARY_BONUS } else { 0 }
+            })
+            .sum();
+        Some((score, positions))
+    }
+
+    /// Score of the best matching cell of `item`, `None` when none matches.
+    pub fn score<T: TableRow>(&self, item: &T) -> Option<i32> {
+        item.cells()
+            .iter()
+            .filter_map(|cell| self.fuzzy_match(cell).map(|(score, _)| score))
+            .max()
+    }
+
+    /// Byte ranges of the matched characters in `text`, consecutive ones
+    /// joined.
+    pub fn find(&self, text: &str) -> Vec<Range<usize>> {
+        let Some((_, positions)) = self.fuzzy_match(text) else {
+            return Vec::new();
+        };
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for (i, (start, c)) in text.char_indices().enumerate() {
+            if !positions.contains(&i) {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(last) if last.end == start => last.end = start + c.len_utf8(),
+                _ => ranges.push(start..start + c.len_utf8()),
+            }
+        }
+        ranges
+    }
+}
+
+/// `rows` split into the ones `filter` keeps, best match first and
+/// otherwise in their current order, and the ones it hides.
+pub fn rank<T: TableRow>(rows: Vec<T>, filter: &Filter) -> (Vec<T>, Vec<T>) {
+    let (mut kept, hidden): (Vec<_>, Vec<_>) = rows
+        .into_iter()
+        .map(|row| (filter.score(&row), row))
+        .partition(|(score, _)| score.is_some());
+    kept.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    let hidden = hidden.into_iter().map(|(_, row)| row).collect();
+    (kept.into_iter().map(|(_, row)| row).collect(), hidden)
+}
+
+/// A change to the filters of a view from the filter prompts.
+pub enum FilterAction {
+    /// The fuzzy filter as typed so far.
+    Fuzzy(Option<Filter>),
+    /// A filter expression; empty input removes it.
+    Query(String),
+}
+
+/// A [`SortedTable`] whose rows can be narrowed with a fuzzy [`Filter`] and
+/// a [`Query`].
+pub trait FilteredTable: SortedTable {
+    fn get_filter(&self) -> Option<&Filter>;
+    fn set_filter(&mut self, filter: Option<Filter>);
+    fn get_query(&self) -> Option<&Query>;
+    fn set_query(&mut self, query: Option<Query>);
+    /// The rows the filters choose from, in the order the view listed
+    /// them.
+    fn get_unfiltered(&self) -> Vec<Self::Item>;
+
+    /// Show only the rows matching the query and `filter`, best match
+    /// first, or in sort order without a fuzzy filter. The selected row
+    /// stays selected while it is shown.
+    fn filter(&mut self, filter: Option<Filter>) {
+        let selected = self.selected_item().map(TableRow::key);
+        let mut rows = self.get_unfiltered();
+        sort_rows(&mut rows, self.get_sort());
+        if let Some(query) = self.get_query() {
+            rows.retain(|row| query.matches(row));
+        }
+        if let Some(filter) = &filter {
+            rows = rank(rows, filter).0;
+        }
+        *self.get_items_mut() = rows;
+        self.set_filter(filter);
+        self.reset_selection();
+        self.reselect(selected);
+    }
+
+    /// Apply the current filters again after the rows changed.
+    fn refilter(&mut self) {
+        let filter = self.get_filter().cloned();
+        self.filter(filter);
+    }
+
+    /// Apply `action`; an `Err` explains an expression that does not parse.
+    fn apply_filter(&mut self, action: FilterAction) -> Result<(), QueryError> {
+        match action {
+            FilterAction::Fuzzy(filter) => self.filter(filter),
+            FilterAction::Query(input) => {
+                let query = match input.trim() {
+                    "" => None,
+                    input => Some(Query::parse::<Self::Item>(input)?),
+                };
+                self.set_query(query);
+                self.refilter();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `status` with the filters of `table` appended.
+pub fn filter_status(status: Option<String>, table: &impl FilteredTable) -> Option<String> {
+    let query = table
+        .get_query()
+        .map(|query| format!("where: {}", query.text));
+    let filter = table
+        .get_filter()
+        .map(|filter| format!("filter: {}", filter.pattern));
+    [status, query, filter]
+        .into_iter()
+        .flatten()
+        .reduce(|a, b| format!("{a} | {b}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::filter::{rank, Filter, FilterAction, FilteredTable};
+    use crate::tui::table_app::TableApp;
+    use crate::tui::table_view::TestRow;
+
+    #[test]
+    fn test_fuzzy_match() {
+        let filter = Filter::new("apw").unwrap();
+        assert_eq!(
+            Some(vec![0, 1, 4]),
+            filter.fuzzy_match("api-web").map(|m| m.1)
+        );
+        assert!(filter.fuzzy_match("web-api").is_none());
+        assert_eq!(vec![0..2, 4..5], filter.find("api-web"));
+
+        // Word starts and runs beat scattered characters.
+        let (close, _) = Filter::new("web").unwrap().fuzzy_match("api-web").unwrap();
+        let (far, _) = Filter::new("web")
+            .unwrap()
+            .fuzzy_match("wide-label")
+            .unwrap();
+        assert!(close > far);
+
+        assert!(Filter::new("Web").unwrap().fuzzy_match("api-web").is_none());
+        assert!(Filter::new("").is_none());
+    }
+
+    #[test]
+    fn test_rank() {
+        let rows = vec![
+            TestRow::new("worker-queue-b", "Running"),
+            TestRow::new("db", "Pending"),
+            TestRow::new("web", "Running"),
+            TestRow::new("webhook", "Running"),
+        ];
+        let (kept, hidden) = rank(rows, &Filter::new("web").unwrap());
+        let names = kept.iter().map(|row| row.name).collect::<Vec<_>>();
+        assert_eq!(vec!["web", "webhook", "worker-queue-b"], names);
+        assert_eq!("db", hidden[0].name);
+    }
+
+    #[test]
+    fn test_unfilter_restores_order() {
+        let rows = vec![
+            TestRow::new("worker-queue-b", "Running"),
+            TestRow::new("db", "Pending"),
+            TestRow::new("web", "Running"),
+            TestRow::new("webhook", "Running"),
+        ];
+        let names =
+            |app: &TableApp<TestRow>| app.items.iter().map(|row| row.name).collect::<Vec<_>>();
+        let mut app = TableApp::new(rows, 0);
+        let original = names(&app);
+
+        app.filter(Filter::new("web"));
+        assert_eq!(vec!["web", "webhook", "worker-queue-b"], names(&app));
+        app.filter(None);
+        assert_eq!(original, names(&app));
+
+        app.apply_filter(FilterAction::Query("status == Pending".to_string()))
+            .unwrap();
+        assert_eq!(vec!["db"], names(&app));
+        app.apply_filter(FilterAction::Query(String::new()))
+            .unwrap();
+        assert_eq!(original, names(&app));
+    }
+}
@@ -0,0 +1,15 @@
+use crate::tui::data::{generate_hpa_recs, Hpa};
+use crate::tui::table_app::TableApp;
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) table: TableApp<Hpa>,
+}
+
+impl App {
+    pub fn new() -> Self {
+        Self {
+            table: TableApp::new(generate_hpa_recs(), 1),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::tui::data::{generate_ingress_recs, generate_service_recs, IngressRule, Service};
+use crate::tui::table_app::TableApp;
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) table: TableApp<IngressRule>,
+    /// The Services the rules route to, to tell live backends from missing ones.
+    pub(crate) services: Vec<Service>,
+}
+
+impl App {
+    pub fn new() -> Self {
+        let services = generate_service_recs();
+        let data_vec = generate_ingress_recs(&services);
+        Self {
+            table: TableApp::new(data_vec, 3),
+            services,
+        }
+    }
+
+    /// The backend Service of the selected rule, `Err` with its name when
+    /// the Service does not exist.
+    pub fn selected_backend(&self) -> Option<Result<&Service, &str>> {
+        let rule = self.table.items.get(self.table.state.selected()?)?;
+        Some(
+            rule.backend_service(&self.services)
+                .ok_or(rule.service.as_str()),
+        )
+    }
+}
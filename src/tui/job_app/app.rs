@@ -0,0 +1,18 @@
+use crate::tui::data::Job;
+use crate::tui::table_app::TableApp;
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) table: TableApp<Job>,
+    pub(crate) scope: String,
+}
+
+impl App {
+    /// `jobs` listed under `scope`, e.g. `cronjob: backup-ab1`.
+    pub fn new(scope: String, jobs: Vec<Job>) -> Self {
+        Self {
+            table: TableApp::new(jobs, 3),
+            scope,
+        }
+    }
+}
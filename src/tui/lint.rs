@@ -0,0 +1,238 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum LintError {
+    #[error("unknown lint rule '{0}', expected one of: {1}")]
+    UnknownRule(String, String),
+}
+
+/// A best practice check run against every container spec.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintRule {
+    MissingLimits,
+    MissingProbes,
+    LatestTag,
+    Privileged,
+    RunAsRoot,
+}
+
+impl LintRule {
+    pub const ALL: [Self; 5] = [
+        Self::MissingLimits,
+        Self::MissingProbes,
+        Self::LatestTag,
+        Self::Privileged,
+        Self::RunAsRoot,
+    ];
+
+    /// The name used to turn the rule off, e.g. `--disable-lint latest-tag`.
+    pub const fn id(self) -> &'static str {
+        match self {
+            Self::MissingLimits => "missing-limits",
+            Self::MissingProbes => "missing-probes",
+            Self::LatestTag => "latest-tag",
+            Self::Privileged => "privileged",
+            Self::RunAsRoot => "run-as-root",
+        }
+    }
+
+    fn check(self, spec: &ContainerSpec) -> Option<String> {
+        match self {
+            Self::MissingLimits => (!spec.limits).then(|| "no CPU/memory limits".to_string()),
+            Self::MissingProbes => match (spec.liveness_probe, spec.readiness_probe) {
+                (true, true) => None,
+                (false, true) => Some("no liveness probe".to_string()),
+                (true, false) => Some("no readiness probe".to_string()),
+                (false, false) => Some("no liveness or readiness probe".to_string()),
+            },
+            Self::LatestTag => match image_tag(&spec.image) {
+                None => Some(format!("image {} has no tag, pulls :latest", spec.image)),
+                Some("latest") => Some(format!("image {} uses :latest", spec.image)),
+                Some(_) => None,
+            },
+            Self::Privileged => spec.privileged.then(|| "runs privileged".to_string()),
+            Self::RunAsRoot => match spec.run_as_user {
+                Some(0) => Some("runs as UID 0".to_string()),
+                None if !spec.run_as_non_root => {
+                    Some("may run as root, runAsNonRoot is not set".to_string())
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+/// The tag of an image reference, `None` when it has neither tag nor digest.
+fn image_tag(image: &str) -> Option<&str> {
+    if image.contains('@') {
+        return Some("digest");
+    }
+    let name = image.rsplit('/').next().unwrap_or(image);
+    name.split_once(':').map(|(_, tag)| tag)
+}
+
+/// The parts of a container spec the lint rules look at.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContainerSpec {
+    pub(crate) name: String,
+    pub(crate) image: String,
+    pub(crate) limits: bool,
+    pub(crate) liveness_probe: bool,
+    pub(crate) readiness_probe: bool,
+    pub(crate) privileged: bool,
+    pub(crate) run_as_user: Option<u32>,
+    pub(crate) run_as_non_root: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finding {
+    pub(crate) rule: LintRule,
+    pub(crate) container: String,
+    pub(crate) message: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}: {}", self.rule, self.container, self.message)
+    }
+}
+
+/// Which rules run; all of them unless turned off.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LintConfig {
+    disabled: BTreeSet<LintRule>,
+}
+
+impl LintConfig {
+    /// A comma separated list of rule ids to turn off.
+    pub fn disable(rules: &str) -> Result<Self, LintError> {
+        let disabled = rules
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(|id| {
+                LintRule::ALL
+                    .into_iter()
+                    .find(|rule| rule.id() == id)
+                    .ok_or_else(|| {
+                        let known = LintRule::ALL.map(LintRule::id).join(", ");
+                        LintError::UnknownRule(id.to_string(), known)
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { disabled })
+    }
+
+    pub fn is_enabled(&self, rule: LintRule) -> bool {
+        !self.disabled.contains(&rule)
+    }
+
+    pub fn enabled(&self) -> impl Iterator<Item = LintRule> + '_ {
+        LintRule::ALL
+            .into_iter()
+            .filter(|rule| self.is_enabled(*rule))
+    }
+
+    pub fn lint(&self, specs: &[ContainerSpec]) -> Vec<Finding> {
+        specs
+            .iter()
+            .flat_map(|spec| {
+                self.enabled().filter_map(|rule| {
+                    rule.check(spec).map(|message| Finding {
+                        rule,
+                        container: spec.name.clone(),
+                        message,
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::lint::{ContainerSpec, LintConfig, LintError, LintRule};
+
+    fn clean_spec() -> ContainerSpec {
+        ContainerSpec {
+            name: "app".to_string(),
+            image: "ghcr.io/acme/api:v2.4.1".to_string(),
+            limits: true,
+            liveness_probe: true,
+            readiness_probe: true,
+            privileged: false,
+            run_as_user: Some(1000),
+            run_as_non_root: true,
+        }
+    }
+
+    #[test]
+    fn test_clean_spec_has_no_findings() {
+        assert!(LintConfig::default().lint(&[clean_spec()]).is_empty());
+
+        let pinned = ContainerSpec {
+            image: "nginx@sha256:abc".to_string(),
+            ..clean_spec()
+        };
+        assert!(LintConfig::default().lint(&[pinned]).is_empty());
+    }
+
+    #[test]
+    fn test_every_rule_fires() {
+        let spec = ContainerSpec {
+            name: "sidecar".to_string(),
+            image: "localhost:5000/busybox".to_string(),
+            limits: false,
+            liveness_probe: false,
+            readiness_probe: true,
+            privileged: true,
+            run_as_user: None,
+            run_as_non_root: false,
+        };
+        let findings = LintConfig::default()
+            .lint(&[clean_spec(), spec])
+            .into_iter()
+            .map(|finding| finding.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                "[missing-limits] sidecar: no CPU/memory limits",
+                "[missing-probes] sidecar: no liveness probe",
+                "[latest-tag] sidecar: image localhost:5000/busybox has no tag, pulls :latest",
+                "[privileged] sidecar: runs privileged",
+                "[run-as-root] sidecar: may run as root, runAsNonRoot is not set",
+            ],
+            findings
+        );
+    }
+
+    #[test]
+    fn test_disable_rules() {
+        let config = LintConfig::disable("latest-tag, run-as-root").unwrap();
+        let spec = ContainerSpec {
+            image: "nginx:latest".to_string(),
+            run_as_user: Some(0),
+            ..clean_spec()
+        };
+
+        assert!(!config.is_enabled(LintRule::LatestTag));
+        assert!(config.lint(std::slice::from_ref(&spec)).is_empty());
+        assert_eq!(2, LintConfig::default().lint(&[spec]).len());
+        assert_eq!(
+            Err(LintError::UnknownRule(
+                "latest".to_string(),
+                "missing-limits, missing-probes, latest-tag, privileged, run-as-root".to_string()
+            )),
+            LintConfig::disable("latest")
+        );
+    }
+}
@@ -0,0 +1,335 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::Write;
+use std::ops::RangeInclusive;
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use itertools::Itertools;
+use thiserror::Error;
+
+use crate::tui::filter::FilteredTable;
+use crate::tui::table_view::TableRow;
+
+#[derive(Debug, Error)]
+pub enum MarksError {
+    #[error("export {path}: {source}")]
+    Export {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("copy: {0}")]
+    Clipboard(std::io::Error),
+}
+
+/// The rows marked in a view, by key so they stay marked while the rows are
+/// sorted or filtered.
+#[derive(Clone, Debug, Default)]
+pub struct Marks {
+    keys: BTreeSet<String>,
+    /// Key of the row a `V` range started at while one is being marked.
+    anchor: Option<String>,
+    /// Outcome of the last bulk action, shown in the footer.
+    pub(crate) notice: Option<String>,
+}
+
+impl Marks {
+    /// Rows from the `V` anchor to `selected` while a range is being marked.
+    pub fn visual_range<T: TableRow>(
+        &self,
+        items: &[T],
+        selected: Option<usize>,
+    ) -> Option<RangeInclusive<usize>> {
+        let anchor = self.anchor.as_ref()?;
+        let anchor = items.iter().position(|item| &item.key() == anchor)?;
+        let selected = selected?;
+        Some(anchor.min(selected)..=anchor.max(selected))
+    }
+
+    /// Mark the row with `key`, or unmark it when it is marked.
+    pub fn toggle(&mut self, key: String) {
+        if !self.keys.remove(&key) {
+            self.keys.insert(key);
+        }
+    }
+
+    /// Indexes of the marked `items`, counting the `V` range.
+    pub fn marked<T: TableRow>(&self, items: &[T], selected: Option<usize>) -> Vec<usize> {
+        let range = self.visual_range(items, selected);
+        items
+            .iter()
+            .enumerate()
+            .filter(|(i, item)| {
+                range.as_ref().is_some_and(|range| range.contains(i))
+                    || self.keys.contains(&item.key())
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// A change to the marks of a view.
+pub enum MarkAction {
+    /// Mark or unmark the selected row.
+    Toggle,
+    /// Start a range at the selected row, or mark the range when one is
+    /// started.
+    Visual,
+    /// Mark every row, or unmark them all when they all are.
+    All,
+    /// Drop the range being marked.
+    CancelVisual,
+}
+
+/// An action on every marked row, or on the selected row when none is.
+pub enum BulkAction {
+    /// Put the keys on the clipboard of the terminal.
+    Copy,
+    /// Write the rows as CSV to a file; an empty name means `<kind>.csv`.
+    Export(String),
+    Delete,
+}
+
+/// OSC 52 escape sequence asking the terminal to put `text` on the
+/// clipboard, which works over ssh too.
+pub fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", BASE64.encode(text))
+}
+
+/// `rows` of `T` as CSV with a header of the column titles; cells spanning
+/// lines are joined with spaces.
+pub fn to_csv<T: TableRow>(rows: &[&T]) -> String {
+    let field = |text: &str| {
+        let text = text.lines().join(" ");
+        if text.contains([',', '"']) {
+            format!("\"{}\"", text.replace('"', "\"\""))
+        } else {
+            text
+        }
+    };
+    let header = T::COLUMNS
+        .iter()
+        .map(|column| field(column.title))
+        .join(",");
+    let lines = rows
+        .iter()
+        .map(|row| row.cells().iter().map(|cell| field(cell)).join(","));
+    std::iter::once(header)
+        .chain(lines)
+        .map(|line| line + "\n")
+        .collect()
+}
+
+/// A [`FilteredTable`] whose rows can be marked to act on several at once.
+pub trait MarkedTable: FilteredTable {
+    fn get_marks(&self) -> &Marks;
+    fn get_marks_mut(&mut self) -> &mut Marks;
+
+    /// Remove the rows with `keys`, wherever the table keeps them.
+    fn delete_rows(&mut self, keys: &BTreeSet<String>);
+
+    /// Indexes of the marked rows, or of the selected row when none is.
+    fn marked_rows(&mut self) -> Vec<usize> {
+        let selected = self.get_state().selected();
+        let marked = self.get_marks().marked(self.get_items(), selected);
+        if marked.is_empty() {
+            selected.into_iter().collect()
+        } else {
+            marked
+        }
+    }
+
+    /// Apply `action`; `false` when it changed nothing, e.g. Esc without a
+    /// range being marked.
+    fn apply_mark(&mut self, action: MarkAction) -> bool {
+        let selected = self.get_state().selected();
+        let key = self.selected_item().map(TableRow::key);
+        let items = self.get_items().iter().map(TableRow::key).collect_vec();
+        let marks = self.get_marks_mut();
+        marks.notice = None;
+        match action {
+            MarkAction::Toggle => {
+                let Some(key) = key else {
+                    return false;
+                };
+                marks.toggle(key);
+            }
+            MarkAction::Visual => match marks.anchor.take() {
+                Some(anchor) => {
+                    let Some(start) = items.iter().position(|item| *item == anchor) else {
+                        return true;
+                    };
+                    let end = selected.unwrap_or(start);
+                    let range = start.min(end)..=start.max(end);
+                    marks.keys.extend(items[range].iter().cloned());
+                }
+                None => marks.anchor = key,
+            },
+            MarkAction::All => {
+                marks.anchor = None;
+                if items.iter().all(|item| marks.keys.contains(item)) {
+                    marks.keys.clear();
+                } else {
+                    marks.keys.extend(items);
+                }
+            }
+            MarkAction::CancelVisual => return marks.anchor.take().is_some(),
+        }
+        true
+    }
+
+    /// Apply `action` to the marked rows and say how it went in the footer;
+    /// an `Err` is also kept for the export prompt.
+    fn apply_bulk(&mut self, action: BulkAction) -> Result<(), MarksError> {
+        let indexes = self.marked_rows();
+        let rows = indexes
+            .iter()
+            .filter_map(|&i| self.get_items().get(i))
+            .collect_vec();
+        let keys = rows.iter().map(|row| row.key()).collect_vec();
+        let kind = Self::Item::KIND;
+        let count = rows.len();
+        let result = match action {
+            BulkAction::Copy => {
+                let keys = keys.join("\n");
+                let mut stdout = std::io::stdout();
+                stdout
+                    .write_all(osc52(&keys).as_bytes())
+                    .and_then(|()| stdout.flush())
+                    .map(|()| format!("copied {count} {kind}"))
+                    .map_err(MarksError::Clipboard)
+            }
+            BulkAction::Export(path) => {
+                let path = match path.trim() {
+                    "" => format!("{kind}.csv"),
+                    path => path.to_string(),
+                };
+                fs::write(&path, to_csv(&rows))
+                    .map(|()| format!("exported {count} {kind} to {path}"))
+                    .map_err(|source| MarksError::Export { path, source })
+            }
+            BulkAction::Delete => {
+                let keys = keys.into_iter().collect();
+                self.delete_rows(&keys);
+                let marks = self.get_marks_mut();
+                marks.keys.retain(|key| !keys.contains(key));
+                marks.anchor = None;
+                self.refilter();
+                Ok(format!("deleted {count} {kind}"))
+            }
+        };
+        self.get_marks_mut().notice = Some(match &result {
+            Ok(notice) => notice.clone(),
+            Err(err) => err.to_string(),
+        });
+        result.map(|_| ())
+    }
+}
+
+/// `status` with the mark count of `table`, or the outcome of its last bulk
+/// action, appended.
+pub fn marks_status(status: Option<String>, table: &mut impl MarkedTable) -> Option<String> {
+    let selected = table.get_state().selected();
+    let marks = table.get_marks();
+    let count = marks.marked(table.get_items(), selected).len();
+    let marks = match (&marks.notice, count) {
+        (Some(notice), _) => Some(notice.clone()),
+        (None, 0) => None,
+        (None, count) => Some(format!("{count} marked")),
+    };
+    [status, marks]
+        .into_iter()
+        .flatten()
+        .reduce(|a, b| format!("{a} | {b}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::data::{generate_pod_recs, Event};
+    use crate::tui::lint::LintConfig;
+    use crate::tui::marks::{
+        marks_status, osc52, to_csv, BulkAction, MarkAction, MarkedTable, Marks,
+    };
+    use crate::tui::pod_app::app::App;
+    use crate::tui::table_app::TableApp;
+    use crate::tui::table_ui::TuiTableState;
+    use crate::tui::table_view::{TableRow, TestRow};
+
+    #[test]
+    fn test_marked() {
+        let rows = [
+            TestRow::new("a", ""),
+            TestRow::new("b", ""),
+            TestRow::new("c", ""),
+            TestRow::new("d", ""),
+        ];
+        let mut marks = Marks::default();
+        marks.keys.insert("d".to_string());
+        assert_eq!(vec![3], marks.marked(&rows, Some(0)));
+
+        marks.anchor = Some("b".to_string());
+        assert_eq!(Some(0..=1), marks.visual_range(&rows, Some(0)));
+        assert_eq!(vec![1, 2, 3], marks.marked(&rows, Some(2)));
+    }
+
+    #[test]
+    fn test_mark_and_delete() {
+        let mut app = App::with_pods(generate_pod_recs(), None, &LintConfig::default()).table;
+        app.apply_mark(MarkAction::Toggle);
+        app.next(true);
+        app.apply_mark(MarkAction::Visual);
+        app.next(true);
+        app.next(true);
+        assert_eq!(vec![0, 1, 2, 3], app.marked_rows());
+        app.apply_mark(MarkAction::Visual);
+        app.apply_mark(MarkAction::Toggle);
+        assert_eq!(vec![0, 1, 2], app.marked_rows());
+        assert_eq!(Some("3 marked".to_string()), marks_status(None, &mut app));
+
+        let kept = app.items[3].key();
+        app.apply_bulk(BulkAction::Delete).unwrap();
+        assert_eq!(17, app.items.len());
+        assert_eq!(17, app.all_items.len());
+        assert_eq!(kept, app.items[0].key());
+        assert_eq!(
+            Some("deleted 3 pods".to_string()),
+            marks_status(None, &mut app)
+        );
+
+        app.apply_mark(MarkAction::All);
+        assert_eq!(17, app.marked_rows().len());
+        app.apply_mark(MarkAction::All);
+        assert_eq!(None, marks_status(None, &mut app));
+    }
+
+    #[test]
+    fn test_mark_and_delete_repeated_event() {
+        let event = |name: &str, last_seen: &str| Event {
+            name: name.to_string(),
+            kind: "Warning".to_string(),
+            reason: "BackOff".to_string(),
+            object: "Pod/api".to_string(),
+            count: "1".to_string(),
+            last_seen: last_seen.to_string(),
+            message: String::new(),
+        };
+        let mut app = TableApp::new(vec![event("api.1", "1m"), event("api.2", "5m")], 3);
+        app.apply_mark(MarkAction::Toggle);
+        assert_eq!(vec![0], app.marked_rows());
+
+        app.apply_bulk(BulkAction::Delete).unwrap();
+        assert_eq!(1, app.items.len());
+        assert_eq!(1, app.all_items.len());
+        assert_eq!("api.2", app.items[0].key());
+    }
+
+    #[test]
+    fn test_export_and_copy() {
+        let a = TestRow::new("api", "Back-off \"restarting\", x3");
+        let b = TestRow::new("web", "Started\nagain").seen("3h");
+        assert_eq!(
+            "Name,Status,Last Seen,Pods\napi,\"Back-off \"\"restarting\"\", x3\",,\nweb,Started again,3h,\n",
+            to_csv(&[&a, &b])
+        );
+        assert_eq!("\x1b]52;c;YXBpCndlYg==\x07", osc52("api\nweb"));
+    }
+}
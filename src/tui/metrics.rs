@@ -0,0 +1,278 @@
+use std::collections::VecDeque;
+
+/// How many samples each series keeps for its sparkline.
+pub const SAMPLES: usize = 12;
+
+const SPARK: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Where usage lands relative to the requests and limits of a workload.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UsageLevel {
+    Normal,
+    OverRequest,
+    NearLimit,
+}
+
+/// Requests and limits of one resource, in millicores or MiB.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quantity {
+    pub(crate) request: u64,
+    pub(crate) limit: u64,
+}
+
+impl Quantity {
+    pub const fn new(request: u64, limit: u64) -> Self {
+        Self { request, limit }
+    }
+
+    /// At or above 90% of the limit is near it, above the request is over.
+    pub const fn level(self, usage: u64) -> UsageLevel {
+        if self.limit > 0 && usage * 10 >= self.limit * 9 {
+            UsageLevel::NearLimit
+        } else if usage > self.request {
+            UsageLevel::OverRequest
+        } else {
+            UsageLevel::Normal
+        }
+    }
+}
+
+/// The most recent samples of one resource, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct Series {
+    samples: VecDeque<u64>,
+}
+
+impl Series {
+    pub fn push(&mut self, sample: u64) {
+        if self.samples.len() == SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    pub fn last(&self) -> Option<u64> {
+        self.samples.back().copied()
+    }
+
+    /// One bar per sample, scaled against `ceiling` (usually the limit) so
+    /// the bars of different rows compare.
+    pub fn sparkline(&self, ceiling: u64) -> String {
+        let ceiling = self
+            .samples
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(0)
+            .max(ceiling)
+            .max(1);
+        self.samples
+            .iter()
+            .map(|sample| {
+                let level = usize::try_from(sample * 7 / ceiling).unwrap_or(7);
+                SPARK[level.min(7)]
+            })
+            .collect()
+    }
+}
+
+/// Usage history of a pod or container together with its requests and limits.
+#[derive(Clone, Debug)]
+pub struct ResourceMetrics {
+    pub(crate) cpu: Series,
+    pub(crate) memory: Series,
+    pub(crate) cpu_spec: Quantity,
+    pub(crate) memory_spec: Quantity,
+}
+
+impl ResourceMetrics {
+    pub fn new(cpu_spec: Quantity, memory_spec: Quantity) -> Self {
+        Self {
+            cpu: Series::default(),
+            memory: Series::default(),
+            cpu_spec,
+            memory_spec,
+        }
+    }
+
+    pub fn record(&mut self, cpu: u64, memory: u64) {
+        self.cpu.push(cpu);
+        self.memory.push(memory);
+    }
+
+    pub fn cpu_text(&self) -> String {
+        self.cpu.last().map_or_else(String::new, |cpu| {
+            format!("{cpu}m {}", self.cpu.sparkline(self.cpu_spec.limit))
+        })
+    }
+
+    pub fn memory_text(&self) -> String {
+        self.memory.last().map_or_else(String::new, |memory| {
+            format!(
+                "{memory}Mi {}",
+                self.memory.sparkline(self.memory_spec.limit)
+            )
+        })
+    }
+
+    pub fn cpu_level(&self) -> UsageLevel {
+        self.cpu_spec.level(self.cpu.last().unwrap_or(0))
+    }
+
+    pub fn memory_level(&self) -> UsageLevel {
+        self.memory_spec.level(self.memory.last().unwrap_or(0))
+    }
+}
+
+/// Where usage samples come from, e.g. the metrics API.
+pub trait MetricsSource {
+    /// The next `(cpu millicores, memory MiB)` sample for `metrics`.
+    fn sample(&mut self, metrics: &ResourceMetrics) -> (u64, u64);
+}
+
+/// Random walk around the requests that now and then runs up against the
+/// limits, so every coloring shows up offline.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FakeMetrics;
+
+impl FakeMetrics {
+    fn walk(previous: Option<u64>, spec: Quantity) -> u64 {
+        use fakeit::misc;
+
+        let start = previous.unwrap_or_else(|| misc::random(spec.request / 2, spec.request));
+        let step = (spec.limit / 8).max(1);
+        let next = start + misc::random(0, 2 * step);
+        next.saturating_sub(step).clamp(1, spec.limit.max(1))
+    }
+}
+
+impl MetricsSource for FakeMetrics {
+    fn sample(&mut self, metrics: &ResourceMetrics) -> (u64, u64) {
+        (
+            Self::walk(metrics.cpu.last(), metrics.cpu_spec),
+            Self::walk(metrics.memory.last(), metrics.memory_spec),
+        )
+    }
+}
+
+/// Requests and limits picked from common sizes, with a few seed samples so
+/// sparklines show right away.
+pub fn generate_metrics() -> ResourceMetrics {
+    use fakeit::misc;
+
+    let cpu_request = misc::random_data(&[100, 250, 500]);
+    let memory_request = misc::random_data(&[128, 256, 512]);
+    let mut metrics = ResourceMetrics::new(
+        Quantity::new(cpu_request, cpu_request * 2),
+        Quantity::new(memory_request, memory_request * 2),
+    );
+    for _ in 0..SAMPLES / 2 {
+        let (cpu, memory) = FakeMetrics.sample(&metrics);
+        metrics.record(cpu, memory);
+    }
+    metrics
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::data::{generate_pod_recs, Pod};
+    use crate::tui::lint::LintConfig;
+    use crate::tui::metrics::{
+        FakeMetrics, MetricsSource, Quantity, ResourceMetrics, Series, UsageLevel, SAMPLES,
+    };
+    use crate::tui::pod_app::app::App;
+    use crate::tui::sort::{SortKey, SortedTable};
+    use crate::tui::table_view::TableRow;
+    use unicode_width::UnicodeWidthStr;
+
+    /// Hands out 100m, 200m, ... so each row sampled uses more than the last.
+    struct Rising(u64);
+
+    impl MetricsSource for Rising {
+        fn sample(&mut self, _metrics: &ResourceMetrics) -> (u64, u64) {
+            self.0 += 100;
+            (self.0, 64)
+        }
+    }
+
+    #[test]
+    fn test_series_keeps_recent_samples() {
+        let mut series = Series::default();
+        for sample in 0..20 {
+            series.push(sample);
+        }
+
+        assert_eq!(Some(19), series.last());
+        assert_eq!(SAMPLES, series.sparkline(0).chars().count());
+    }
+
+    #[test]
+    fn test_sparkline_scales_to_ceiling() {
+        let mut series = Series::default();
+        for sample in [0, 50, 100] {
+            series.push(sample);
+        }
+
+        assert_eq!("▁▄█", series.sparkline(100));
+        assert_eq!("▁▂▄", series.sparkline(200));
+    }
+
+    #[test]
+    fn test_usage_level() {
+        let spec = Quantity::new(250, 500);
+
+        assert_eq!(UsageLevel::Normal, spec.level(250));
+        assert_eq!(UsageLevel::OverRequest, spec.level(251));
+        assert_eq!(UsageLevel::NearLimit, spec.level(450));
+    }
+
+    #[test]
+    fn test_fake_metrics_stay_within_limits() {
+        let mut metrics = ResourceMetrics::new(Quantity::new(100, 200), Quantity::new(128, 256));
+        for _ in 0..100 {
+            let (cpu, memory) = FakeMetrics.sample(&metrics);
+            metrics.record(cpu, memory);
+            assert!((1..=200).contains(&cpu));
+            assert!((1..=256).contains(&memory));
+        }
+
+        assert!(metrics.cpu_text().ends_with(&metrics.cpu.sparkline(200)));
+        assert!(metrics.memory_text().contains("Mi "));
+    }
+
+    #[test]
+    fn test_metrics_text_width() {
+        let mut metrics = ResourceMetrics::new(Quantity::new(250, 500), Quantity::new(128, 256));
+        assert_eq!(
+            (0, 0),
+            (metrics.cpu_text().width(), metrics.memory_text().width())
+        );
+
+        metrics.record(1250, 64);
+        metrics.record(250, 128);
+        // "250m █▂", "128Mi ▂▄"
+        assert_eq!(
+            (7, 8),
+            (metrics.cpu_text().width(), metrics.memory_text().width())
+        );
+    }
+
+    #[test]
+    fn test_sampling_resorts_by_usage_only() {
+        let mut app = App::with_pods(generate_pod_recs(), None, &LintConfig::default());
+        let keys = |rows: &[_]| rows.iter().map(TableRow::key).collect::<Vec<_>>();
+        app.table.sort(vec![SortKey::ascending(0)]);
+        let by_name = keys(&app.table.items);
+        app.sample_metrics(&mut Rising(0));
+        assert_eq!(by_name, keys(&app.table.items));
+
+        app.table.sort(vec![SortKey {
+            column: Pod::CPU,
+            descending: true,
+        }]);
+        app.sample_metrics(&mut Rising(0));
+        let mut busiest_first = keys(&app.table.all_items);
+        busiest_first.reverse();
+        assert_eq!(busiest_first, keys(&app.table.items));
+    }
+}
@@ -1,22 +1,93 @@
+mod api_resource_app;
+mod cert_app;
+mod certs;
+mod columns;
+mod config_app;
+mod config_entry_app;
 mod container_app;
+mod cron;
+mod cronjob_app;
 mod data;
+mod discovery;
+mod event_app;
+mod filter;
+mod hpa_app;
+mod ingress_app;
+mod job_app;
+mod lint;
+mod marks;
+mod metrics;
+mod node_app;
+mod overview_app;
 mod pod_app;
+mod prompt;
+mod query;
+mod resource_app;
 mod rs_app;
+mod search;
+mod selector;
+mod service_app;
+mod sort;
 mod style;
+mod table_app;
 mod table_ui;
+mod table_view;
+mod xray;
+mod xray_app;
+mod yaml_app;
 
+use std::path::Path;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 use std::{error::Error, io};
 
+use crate::tui::certs::{FakeSecrets, SecretSource, SnapshotSecrets};
+use crate::tui::columns::{ChooserAction, ColumnChooser, ColumnStore};
+use crate::tui::data::{
+    generate_cronjob_recs, generate_deployment_rs_recs, generate_pod_container_recs, HOUR_SECS,
+};
+use crate::tui::discovery::{discover, Discovery, DiscoveryError, FakeDiscovery};
+use crate::tui::filter::{Filter, FilterAction, FilteredTable};
+use crate::tui::lint::LintConfig;
+use crate::tui::marks::{BulkAction, MarkAction, MarkedTable, MarksError};
+use crate::tui::metrics::FakeMetrics;
+use crate::tui::overview_app::app::Tile;
+use crate::tui::prompt::{Prompt, PromptAction, PromptKind};
+use crate::tui::query::QueryError;
+use crate::tui::search::{Search, SearchAction, SearchTable};
+use crate::tui::selector::LabelSelector;
+use crate::tui::sort::{SortAction, SortedTable};
+use crate::tui::table_ui::{render_column_chooser, render_notice, Motion, TuiTableState};
+use crate::tui::xray::{XrayNode, XrayObject};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+        MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::prelude::*;
-use crate::tui::table_ui::TuiTableState;
 
-pub fn run() -> Result<(), Box<dyn Error>> {
+/// `disabled_lints` is a comma separated list of lint rules to skip.
+/// `tls_snapshot` is a file or directory of `kubectl get secrets -o json`
+/// output to read TLS Secrets from instead of the fake cluster.
+/// `wraparound` false stops `j`/`k` at the first and last row.
+pub fn run(
+    disabled_lints: Option<&str>,
+    tls_snapshot: Option<&Path>,
+    wraparound: bool,
+) -> Result<(), Box<dyn Error>> {
+    let lint = LintConfig::disable(disabled_lints.unwrap_or_default())?;
+    let mut columns = columns::default_path()
+        .map(ColumnStore::load)
+        .transpose()?
+        .unwrap_or_default();
+    let secrets: Box<dyn SecretSource> = match tls_snapshot {
+        Some(path) => Box::new(SnapshotSecrets::new(path)),
+        None => Box::new(FakeSecrets),
+    };
+
     // setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -24,7 +95,13 @@ pub fn run() -> Result<(), Box<dyn Error>> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let res = run_app(&mut terminal);
+    let res = run_app(
+        &mut terminal,
+        secrets.as_ref(),
+        &lint,
+        &mut columns,
+        wraparound,
+    );
 
     // restore terminal
     disable_raw_mode()?;
@@ -42,89 +119,1190 @@ pub fn run() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// How often pod and container usage is sampled while idle.
+const METRICS_INTERVAL: Duration = Duration::from_secs(2);
+
 #[derive(Clone, Debug)]
 enum Apps {
+    Overview { app: overview_app::app::App },
     Rs { app: rs_app::app::App },
     Pod { app: pod_app::app::App },
     Container { app: container_app::app::App },
+    Event { app: event_app::app::App },
+    Node { app: node_app::app::App },
+    Service { app: service_app::app::App },
+    Ingress { app: ingress_app::app::App },
+    Config { app: config_app::app::App },
+    ConfigEntry { app: config_entry_app::app::App },
+    CronJob { app: cronjob_app::app::App },
+    Job { app: job_app::app::App },
+    Hpa { app: hpa_app::app::App },
+    ApiResource { app: api_resource_app::app::App },
+    Resource { app: resource_app::app::App },
+    Yaml { app: yaml_app::app::App },
+    Xray { app: xray_app::app::App },
+    Cert { app: cert_app::app::App },
 }
 
-fn run_app<B: Backend>(terminal: &mut Terminal<B>) -> io::Result<()> {
-    let mut app_holder = Apps::Rs {
-        app: rs_app::app::App::new(),
+/// Evaluate `$body` with `$table` bound to the [`table_app::TableApp`] of the current
+/// view, giving `Some` of its value, or `None` for views without one.
+macro_rules! with_table {
+    ($app_holder:expr, $table:ident => $body:expr) => {
+        match $app_holder {
+            Apps::Rs { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::Pod { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::Container { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::Event { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::Node { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::Service { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::Ingress { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::Config { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::ConfigEntry { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::CronJob { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::Job { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::Hpa { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::ApiResource { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            Apps::Cert { app } => {
+                let $table = &mut app.table;
+                Some($body)
+            }
+            _ => None,
+        }
+    };
+}
+
+fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    secrets: &dyn SecretSource,
+    lint: &LintConfig,
+    columns: &mut ColumnStore,
+    wraparound: bool,
+) -> io::Result<()> {
+    let mut app_holder = Apps::Overview {
+        app: overview_app::app::App::new(),
     };
     let mut history: Vec<Rc<Apps>> = Vec::new();
+    let mut prompt: Option<Prompt> = None;
+    let mut chooser: Option<ColumnChooser> = None;
+    // Digits typed before a movement key, e.g. the 5 of `5j`.
+    let mut pending_count: Option<usize> = None;
+    // Why the last key did nothing, shown until the next one.
+    let mut notice: Option<String> = None;
+    let discovery = discover(&FakeDiscovery);
+    let mut metrics = FakeMetrics;
+    // Samples are due on time however busy the keyboard is.
+    let mut next_sample = Instant::now() + METRICS_INTERVAL;
     loop {
+        // Views take their column layout as they are shown, so a change in
+        // the chooser also reaches the views of that kind in the history.
+        with_table!(&mut app_holder, table => table.load_layout(columns));
+        if Instant::now() >= next_sample {
+            match &mut app_holder {
+                Apps::Pod { app } => app.sample_metrics(&mut metrics),
+                Apps::Container { app } => app.sample_metrics(&mut metrics),
+                _ => {}
+            }
+            next_sample = Instant::now() + METRICS_INTERVAL;
+        }
+        terminal.draw(|f| {
+            // Drawn into the views themselves, which keep where their rows
+            // scrolled to and how many fit.
+            match &mut app_holder {
+                Apps::Overview { app: overview_app } => {
+                    overview_app::ui::ui(f, overview_app, prompt.as_ref());
+                }
+                Apps::Rs { app: rs_app } => {
+                    rs_app::ui::ui(f, rs_app, prompt.as_ref());
+                }
+                Apps::Pod { app: pod_app } => {
+                    pod_app::ui::ui(f, pod_app, prompt.as_ref());
+                }
+                Apps::Container { app: container_app } => {
+                    container_app::ui::ui(f, container_app, prompt.as_ref());
+                }
+                Apps::Event { app: event_app } => {
+                    event_app::ui::ui(f, event_app, prompt.as_ref());
+                }
+                Apps::Node { app: node_app } => {
+                    node_app::ui::ui(f, node_app, prompt.as_ref());
+                }
+                Apps::Service { app: service_app } => {
+                    service_app::ui::ui(f, service_app, prompt.as_ref());
+                }
+                Apps::Ingress { app: ingress_app } => {
+                    ingress_app::ui::ui(f, ingress_app, prompt.as_ref());
+                }
+                Apps::Config { app: config_app } => {
+                    config_app::ui::ui(f, config_app, prompt.as_ref());
+                }
+                Apps::ConfigEntry { app: entry_app } => {
+                    config_entry_app::ui::ui(f, entry_app, prompt.as_ref());
+                }
+                Apps::CronJob { app: cronjob_app } => {
+                    cronjob_app::ui::ui(f, cronjob_app, prompt.as_ref());
+                }
+                Apps::Job { app: job_app } => {
+                    job_app::ui::ui(f, job_app, prompt.as_ref());
+                }
+                Apps::Hpa { app: hpa_app } => {
+                    hpa_app::ui::ui(f, hpa_app, prompt.as_ref());
+                }
+                Apps::ApiResource {
+                    app: api_resource_app,
+                } => {
+                    api_resource_app::ui::ui(f, api_resource_app, prompt.as_ref());
+                }
+                Apps::Resource { app: resource_app } => {
+                    resource_app::ui::ui(f, resource_app, prompt.as_ref());
+                }
+                Apps::Yaml { app: yaml_app } => {
+                    yaml_app::ui::ui(f, yaml_app, prompt.as_ref());
+                }
+                Apps::Xray { app: xray_app } => {
+                    xray_app::ui::ui(f, xray_app, prompt.as_ref());
+                }
+                Apps::Cert { app: cert_app } => {
+                    cert_app::ui::ui(f, cert_app, prompt.as_ref());
+                }
+            }
+            if let Some(chooser) = &chooser {
+                render_column_chooser(f, chooser);
+            }
+            if let Some(notice) = &notice {
+                render_notice(f, notice);
+            }
+        })?;
+        if !event::poll(next_sample.saturating_duration_since(Instant::now()))? {
+            continue;
+        }
+        let key = match event::read()? {
+            Event::Key(key) => key,
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row: 0,
+                modifiers,
+            }) if prompt.is_none() && chooser.is_none() => {
+                let add = modifiers.intersects(KeyModifiers::SHIFT | KeyModifiers::CONTROL);
+                let width = terminal.size()?.width;
+                let _ = sort_table(
+                    &mut app_holder,
+                    SortAction::Click {
+                        x: column,
+                        width,
+                        add,
+                    },
+                );
+                continue;
+            }
+            _ => continue,
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        notice = None;
+        if let Some(open_prompt) = prompt.as_mut() {
+            match open_prompt.handle_key(key) {
+                PromptAction::Continue if open_prompt.kind == PromptKind::Search => {
+                    let search = Search::new(&open_prompt.input);
+                    search_table(&mut app_holder, SearchAction::Update(search));
+                }
+                PromptAction::Continue
+                    if matches!(open_prompt.kind, PromptKind::Filter | PromptKind::Query) =>
+                {
+                    // Expressions apply once they parse; errors wait for Enter.
+                    let action = filter_action(open_prompt.kind, &open_prompt.input);
+                    let _ = filter_table(&mut app_holder, action);
+                }
+                PromptAction::Continue => {}
+                PromptAction::Cancel => {
+                    match open_prompt.kind {
+                        PromptKind::Search => {
+                            search_table(&mut app_holder, SearchAction::Update(None));
+                        }
+                        PromptKind::Filter | PromptKind::Query => {
+                            let action = filter_action(open_prompt.kind, &open_prompt.initial);
+                            let _ = filter_table(&mut app_holder, action);
+                        }
+                        _ => {}
+                    }
+                    prompt = None;
+                }
+                PromptAction::Submit(input) => {
+                    match submit_prompt(
+                        &mut app_holder,
+                        &mut history,
+                        &discovery,
+                        secrets,
+                        lint,
+                        open_prompt.kind,
+                        &input,
+                    ) {
+                        Ok(()) => prompt = None,
+                        Err(err) => open_prompt.error = Some(err),
+                    }
+                }
+            }
+            continue;
+        }
+        if let Some(open_chooser) = chooser.as_mut() {
+            if let ChooserAction::Close = open_chooser.handle_key(key, columns) {
+                chooser = None;
+            }
+            continue;
+        }
+        if let KeyCode::Char(digit @ '0'..='9') = key.code {
+            // A lone 0 is not a count.
+            if navigable(&app_holder) && (digit != '0' || pending_count.is_some()) {
+                let digit = digit.to_digit(10).unwrap_or_default() as usize;
+                let count = pending_count.unwrap_or(0).saturating_mul(10);
+                pending_count = Some(count.saturating_add(digit));
+                continue;
+            }
+        }
+        let count = pending_count.take();
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let motion = match key.code {
+            // Single steps are left to the views, which may wrap around.
+            KeyCode::Char('j') | KeyCode::Down if count.is_some() => Some(Motion::Down),
+            KeyCode::Char('k') | KeyCode::Up if count.is_some() => Some(Motion::Up),
+            KeyCode::PageDown => Some(Motion::PageDown),
+            KeyCode::PageUp => Some(Motion::PageUp),
+            KeyCode::Char('d') if ctrl => Some(Motion::HalfPageDown),
+            KeyCode::Char('u') if ctrl => Some(Motion::HalfPageUp),
+            KeyCode::Home | KeyCode::Char('g') => Some(Motion::First),
+            KeyCode::End | KeyCode::Char('G') => Some(Motion::Last),
+            _ => None,
+        };
+        if let Some(motion) = motion {
+            if move_table(&mut app_holder, motion, count).is_some() {
+                continue;
+            }
+        }
+        if key.code == KeyCode::Char('o') {
+            chooser = column_chooser(&mut app_holder);
+            if chooser.is_some() {
+                continue;
+            }
+        }
+        if key.code == KeyCode::Char('S') {
+            if let Some(order) = sort_order(&mut app_holder) {
+                prompt = Some(Prompt::new(PromptKind::Sort, order));
+                continue;
+            }
+        }
+        let mark_action = match key.code {
+            KeyCode::Char(' ') => Some(MarkAction::Toggle),
+            KeyCode::Char('V') => Some(MarkAction::Visual),
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(MarkAction::All)
+            }
+            KeyCode::Esc => Some(MarkAction::CancelVisual),
+            _ => None,
+        };
+        if let Some(action) = mark_action {
+            if mark_table(&mut app_holder, action) == Some(true) {
+                continue;
+            }
+        }
+        if key.code == KeyCode::Char('y') && bulk_table(&mut app_holder, BulkAction::Copy).is_some()
+        {
+            continue;
+        }
+        if matches!(key.code, KeyCode::Char('x' | 'D')) {
+            if let Some(count) = marked_count(&mut app_holder).filter(|&count| count > 0) {
+                let kind = match key.code {
+                    KeyCode::Char('x') => PromptKind::Export,
+                    _ => PromptKind::Delete(count),
+                };
+                prompt = Some(Prompt::new(kind, String::new()));
+                continue;
+            }
+        }
+        let filter_kind = match key.code {
+            KeyCode::Char('f') => Some(PromptKind::Filter),
+            KeyCode::Char('w') => Some(PromptKind::Query),
+            _ => None,
+        };
+        if let Some(kind) = filter_kind {
+            if let Some(input) = filter_input(&mut app_holder, kind) {
+                prompt = Some(Prompt::new(kind, input));
+                continue;
+            }
+        }
+        let search_action = match key.code {
+            KeyCode::Char('n') => Some(SearchAction::Next(true)),
+            KeyCode::Char('N') => Some(SearchAction::Next(false)),
+            _ => None,
+        };
+        if let Some(action) = search_action {
+            if search_table(&mut app_holder, action).is_some() {
+                continue;
+            }
+        }
+        if key.code == KeyCode::Char('/')
+            && search_table(&mut app_holder, SearchAction::Update(None)).is_some()
+        {
+            prompt = Some(Prompt::new(PromptKind::Search, String::new()));
+            continue;
+        }
+        let sort_action = match key.code {
+            KeyCode::Char('>') => Some(SortAction::Step(true)),
+            KeyCode::Char('<') => Some(SortAction::Step(false)),
+            KeyCode::Char('~') => Some(SortAction::Reverse),
+            _ => None,
+        };
+        if let Some(action) = sort_action {
+            if sort_table(&mut app_holder, action).is_some() {
+                continue;
+            }
+        }
+        if let Some(text) = unsupported(&app_holder, key.code) {
+            notice = Some(text.to_string());
+            continue;
+        }
         match &mut app_holder {
+            Apps::Overview { app: overview_app } => {
+                use KeyCode::{BackTab, Char, Down, Enter, Esc, Tab, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down | Tab => overview_app.next(wraparound),
+                    Char('k') | Up | BackTab => overview_app.previous(wraparound),
+                    Char('c' | 'C') => overview_app.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Char('r') => {
+                        history.push(Rc::new(app_holder.clone())); // Save current state
+                        app_holder = Apps::Rs {
+                            app: rs_app::app::App::new(),
+                        };
+                    }
+                    Char('x') => {
+                        history.push(Rc::new(app_holder.clone())); // Save current state
+                        app_holder = Apps::Xray {
+                            app: xray_app::app::App::new(),
+                        };
+                    }
+                    Enter => {
+                        let new_app_holder = match overview_app.selected_tile() {
+                            Some(Tile::Nodes) => Apps::Node {
+                                app: node_app::app::App::with_nodes(
+                                    overview_app.not_ready_nodes(),
+                                    Some("not ready".to_string()),
+                                ),
+                            },
+                            Some(Tile::Headroom) => Apps::Node {
+                                app: node_app::app::App::with_nodes(
+                                    overview_app.nodes_by_cpu(),
+                                    Some("busiest first".to_string()),
+                                ),
+                            },
+                            Some(Tile::Pods) => Apps::Pod {
+                                app: pod_app::app::App::with_pods(
+                                    overview_app.problem_pods(),
+                                    Some("not running".to_string()),
+                                    lint,
+                                ),
+                            },
+                            Some(Tile::Warnings) => Apps::Event {
+                                app: event_app::app::App::warnings_within(
+                                    &overview_app.events,
+                                    HOUR_SECS,
+                                ),
+                            },
+                            Some(Tile::Restarts) => Apps::Pod {
+                                app: pod_app::app::App::with_pods(
+                                    overview_app.restarting_pods(),
+                                    Some("most restarts first".to_string()),
+                                    lint,
+                                ),
+                            },
+                            None => continue,
+                        };
+                        history.push(Rc::new(app_holder.clone())); // Save current state
+                        app_holder = new_app_holder;
+                    }
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
             Apps::Rs { app: rs_app } => {
-                terminal.draw(|f| rs_app::ui::ui(f, &mut rs_app.clone()))?;
-                if let Event::Key(key) = event::read()? {
-                    if key.kind == KeyEventKind::Press {
-                        use KeyCode::{Char, Down, Enter, Up};
-                        match key.code {
-                            Char('q') => return Ok(()),
-                            Char('j') | Down => rs_app.next(),
-                            Char('k') | Up => rs_app.previous(),
-                            Char('c' | 'C') => rs_app.next_color(),
-                            Enter => {
-                                let new_app_holder = Apps::Pod {
-                                    app: pod_app::app::App::new(),
-                                };
-                                history.push(Rc::new(app_holder.clone())); // Save current state
-                                app_holder = new_app_holder;
-                            }
-                            _ => {}
+                use KeyCode::{Char, Down, Enter, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => rs_app.table.next(wraparound),
+                    Char('k') | Up => rs_app.table.previous(wraparound),
+                    Char('c' | 'C') => rs_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Char('l') => prompt = Some(selector_prompt(rs_app.table.selector.as_ref())),
+                    Char('e') => {
+                        if let Some(rs) = rs_app.table.selected_item() {
+                            let object = format!("ReplicaSet/{}", rs.name);
+                            history.push(Rc::new(app_holder.clone())); // Save current state
+                            app_holder = Apps::Event {
+                                app: event_app::app::App::for_object(object),
+                            };
+                        }
+                    }
+                    Char('E') => {
+                        history.push(Rc::new(app_holder.clone())); // Save current state
+                        app_holder = Apps::Event {
+                            app: event_app::app::App::new(),
+                        };
+                    }
+                    Enter => {
+                        let mut pod_app = pod_app::app::App::new(lint);
+                        pod_app.table.set_selector(rs_app.table.selector.clone());
+                        let new_app_holder = Apps::Pod { app: pod_app };
+                        history.push(Rc::new(app_holder.clone())); // Save current state
+                        app_holder = new_app_holder;
+                    }
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
                         }
                     }
+                    _ => {}
                 }
             }
             Apps::Pod { app: pod_app } => {
-                terminal.draw(|f| pod_app::ui::ui(f, &mut pod_app.clone()))?;
-                if let Event::Key(key) = event::read()? {
-                    if key.kind == KeyEventKind::Press {
-                        use KeyCode::{Char, Down, Enter, Esc, Up};
-                        match key.code {
-                            Char('q') => return Ok(()),
-                            Char('j') | Down => pod_app.next(),
-                            Char('k') | Up => pod_app.previous(),
-                            Char('c' | 'C') => pod_app.next_color(),
-                            Enter => {
-                                let new_app_holder = Apps::Container {
-                                    app: container_app::app::App::new(),
-                                };
-                                history.push(Rc::new(app_holder.clone())); // Save current state
-                                app_holder = new_app_holder;
-                            }
-                            Esc => {
-                                if let Some(previous_app) = history.pop() {
-                                    app_holder = (*previous_app).clone();
-                                }
-                            }
-                            _ => {}
+                use KeyCode::{Char, Down, Enter, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => pod_app.table.next(wraparound),
+                    Char('k') | Up => pod_app.table.previous(wraparound),
+                    Char('c' | 'C') => pod_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Char('l') => prompt = Some(selector_prompt(pod_app.table.selector.as_ref())),
+                    Char('L') => pod_app.show_lint = !pod_app.show_lint,
+                    Char('e') => {
+                        if let Some(pod) = pod_app.table.selected_item() {
+                            let object = format!("Pod/{}", pod.name);
+                            history.push(Rc::new(app_holder.clone())); // Save current state
+                            app_holder = Apps::Event {
+                                app: event_app::app::App::for_object(object),
+                            };
                         }
                     }
+                    Char('E') => {
+                        history.push(Rc::new(app_holder.clone())); // Save current state
+                        app_holder = Apps::Event {
+                            app: event_app::app::App::new(),
+                        };
+                    }
+                    Enter => {
+                        if let Some(pod) = pod_app.table.selected_item() {
+                            let new_app_holder = Apps::Container {
+                                app: container_app::app::App::for_pod(
+                                    &pod.name,
+                                    generate_pod_container_recs(pod),
+                                    lint,
+                                ),
+                            };
+                            history.push(Rc::new(app_holder.clone())); // Save current state
+                            app_holder = new_app_holder;
+                        }
+                    }
+                    Esc if pod_app.show_lint => pod_app.show_lint = false,
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
                 }
             }
             Apps::Container { app: container_app } => {
-                terminal.draw(|f| container_app::ui::ui(f, &mut container_app.clone()))?;
-                if let Event::Key(key) = event::read()? {
-                    if key.kind == KeyEventKind::Press {
-                        use KeyCode::{Char, Down, Esc, Up};
-                        match key.code {
-                            Char('q') => return Ok(()),
-                            Char('j') | Down => container_app.next(),
-                            Char('k') | Up => container_app.previous(),
-                            Char('c' | 'C') => container_app.next_color(),
-                            Esc => {
-                                if let Some(previous_app) = history.pop() {
-                                    app_holder = (*previous_app).clone();
-                                }
+                use KeyCode::{Char, Down, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => container_app.table.next(wraparound),
+                    Char('k') | Up => container_app.table.previous(wraparound),
+                    Char('c' | 'C') => container_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Char('L') => container_app.show_lint = !container_app.show_lint,
+                    Esc if container_app.show_lint => container_app.show_lint = false,
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::Event { app: event_app } => {
+                use KeyCode::{Char, Down, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => event_app.table.next(wraparound),
+                    Char('k') | Up => event_app.table.previous(wraparound),
+                    Char('c' | 'C') => event_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::Node { app: node_app } => {
+                use KeyCode::{Char, Down, Enter, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => node_app.table.next(wraparound),
+                    Char('k') | Up => node_app.table.previous(wraparound),
+                    Char('c' | 'C') => node_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Enter => {
+                        if let Some(node) = node_app.table.selected_item() {
+                            let new_app_holder = Apps::Pod {
+                                app: pod_app::app::App::for_node(node.name.clone(), lint),
+                            };
+                            history.push(Rc::new(app_holder.clone())); // Save current state
+                            app_holder = new_app_holder;
+                        }
+                    }
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::Service { app: service_app } => {
+                use KeyCode::{Char, Down, Enter, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => service_app.table.next(wraparound),
+                    Char('k') | Up => service_app.table.previous(wraparound),
+                    Char('c' | 'C') => service_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Enter => {
+                        if let Some(Ok(pod_app)) = service_app
+                            .table
+                            .selected_item()
+                            .map(|service| pod_app::app::App::for_service(service, lint))
+                        {
+                            history.push(Rc::new(app_holder.clone())); // Save current state
+                            app_holder = Apps::Pod { app: pod_app };
+                        }
+                    }
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::Ingress { app: ingress_app } => {
+                use KeyCode::{Char, Down, Enter, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => ingress_app.table.next(wraparound),
+                    Char('k') | Up => ingress_app.table.previous(wraparound),
+                    Char('c' | 'C') => ingress_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Enter => {
+                        let Some(backend) = ingress_app.selected_backend() else {
+                            continue;
+                        };
+                        let service_app = match backend {
+                            Ok(service) => service_app::app::App::with_services(
+                                vec![service.clone()],
+                                Some(format!("backend: {}", service.name)),
+                            ),
+                            Err(name) => service_app::app::App::with_services(
+                                Vec::new(),
+                                Some(format!("backend: {name} not found")),
+                            ),
+                        };
+                        history.push(Rc::new(app_holder.clone())); // Save current state
+                        app_holder = Apps::Service { app: service_app };
+                    }
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::Config { app: config_app } => {
+                use KeyCode::{Char, Down, Enter, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => config_app.table.next(wraparound),
+                    Char('k') | Up => config_app.table.previous(wraparound),
+                    Char('c' | 'C') => config_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Enter => {
+                        if let Some(object) = config_app.table.selected_item() {
+                            let new_app_holder = Apps::ConfigEntry {
+                                app: config_entry_app::app::App::new(object),
+                            };
+                            history.push(Rc::new(app_holder.clone())); // Save current state
+                            app_holder = new_app_holder;
+                        }
+                    }
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::ConfigEntry { app: entry_app } => {
+                use KeyCode::{Char, Down, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => entry_app.table.next(wraparound),
+                    Char('k') | Up => entry_app.table.previous(wraparound),
+                    Char('c' | 'C') => entry_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Char('r') => entry_app.toggle_reveal(),
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::CronJob { app: cronjob_app } => {
+                use KeyCode::{Char, Down, Enter, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => cronjob_app.table.next(wraparound),
+                    Char('k') | Up => cronjob_app.table.previous(wraparound),
+                    Char('c' | 'C') => cronjob_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Char('t') => cronjob_app.trigger_selected(),
+                    Enter => {
+                        if let Some(cronjob) = cronjob_app.table.selected_item() {
+                            let new_app_holder = Apps::Job {
+                                app: job_app::app::App::new(
+                                    format!("cronjob: {}", cronjob.name),
+                                    cronjob.jobs.clone(),
+                                ),
+                            };
+                            history.push(Rc::new(app_holder.clone())); // Save current state
+                            app_holder = new_app_holder;
+                        }
+                    }
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::Job { app: job_app } => {
+                use KeyCode::{Char, Down, Enter, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => job_app.table.next(wraparound),
+                    Char('k') | Up => job_app.table.previous(wraparound),
+                    Char('c' | 'C') => job_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Enter => {
+                        if let Some(job) = job_app.table.selected_item() {
+                            let new_app_holder = Apps::Pod {
+                                app: pod_app::app::App::for_job(job, lint),
+                            };
+                            history.push(Rc::new(app_holder.clone())); // Save current state
+                            app_holder = new_app_holder;
+                        }
+                    }
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::Hpa { app: hpa_app } => {
+                use KeyCode::{Char, Down, Enter, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => hpa_app.table.next(wraparound),
+                    Char('k') | Up => hpa_app.table.previous(wraparound),
+                    Char('c' | 'C') => hpa_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Enter => {
+                        let Some(hpa) = hpa_app.table.selected_item() else {
+                            continue;
+                        };
+                        let Some(deployment) = hpa.deployment() else {
+                            notice = Some(format!("target {} has no ReplicaSets", hpa.reference));
+                            continue;
+                        };
+                        let Ok(replicas) = hpa.replicas.parse() else {
+                            notice = Some(format!("{} has not reported its replicas", hpa.name));
+                            continue;
+                        };
+                        let mut rs_app = rs_app::app::App::with_replicasets(
+                            generate_deployment_rs_recs(deployment, replicas),
+                        );
+                        rs_app.scope =
+                            Some(format!("hpa: {} | deployment: {deployment}", hpa.name));
+                        history.push(Rc::new(app_holder.clone())); // Save current state
+                        app_holder = Apps::Rs { app: rs_app };
+                    }
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::ApiResource {
+                app: api_resource_app,
+            } => {
+                use KeyCode::{Char, Down, Enter, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => api_resource_app.table.next(wraparound),
+                    Char('k') | Up => api_resource_app.table.previous(wraparound),
+                    Char('c' | 'C') => api_resource_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Enter => {
+                        if let Some(resource) = api_resource_app.table.selected_item() {
+                            let new_app_holder = Apps::Resource {
+                                app: resource_app::app::App::new(resource.clone()),
+                            };
+                            history.push(Rc::new(app_holder.clone())); // Save current state
+                            app_holder = new_app_holder;
+                        }
+                    }
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::Resource { app: resource_app } => {
+                use KeyCode::{Char, Down, Enter, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => resource_app.next(wraparound),
+                    Char('k') | Up => resource_app.previous(wraparound),
+                    Char('c' | 'C') => resource_app.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Char('y') | Enter => {
+                        let kind = resource_app.resource.kind.clone();
+                        if let Some(row) = resource_app.selected_item() {
+                            let title = format!("{kind}/{}", row.cells[0]);
+                            let new_app_holder = Apps::Yaml {
+                                app: yaml_app::app::App::new(title, &row.object),
+                            };
+                            history.push(Rc::new(app_holder.clone())); // Save current state
+                            app_holder = new_app_holder;
+                        }
+                    }
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::Yaml { app: yaml_app } => {
+                use KeyCode::{Char, Down, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => yaml_app.next(wraparound),
+                    Char('k') | Up => yaml_app.previous(wraparound),
+                    Char('c' | 'C') => yaml_app.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Apps::Xray { app: xray_app } => {
+                use KeyCode::{Char, Down, Enter, Esc, Left, Right, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => xray_app.next(wraparound),
+                    Char('k') | Up => xray_app.previous(wraparound),
+                    Char('l') | Right => xray_app.expand(),
+                    Char('h') | Left => xray_app.collapse(),
+                    Char(' ') => xray_app.toggle(),
+                    Char('+') => xray_app.set_expanded_all(true),
+                    Char('-') => xray_app.set_expanded_all(false),
+                    Char('c' | 'C') => xray_app.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Enter => {
+                        let (Some(node), Some(path)) =
+                            (xray_app.selected_node(), xray_app.selected_path())
+                        else {
+                            continue;
+                        };
+                        let new_app_holder = match &node.object {
+                            XrayObject::Deployment(name) => {
+                                let mut rs_app =
+                                    rs_app::app::App::with_replicasets(node.replicasets());
+                                rs_app.scope = Some(format!("deployment: {name}"));
+                                Apps::Rs { app: rs_app }
                             }
-                            _ => {}
+                            XrayObject::ReplicaSet(rs) => Apps::Pod {
+                                app: pod_app::app::App::with_pods(
+                                    node.pods(),
+                                    Some(format!("replicaset: {}", rs.name)),
+                                    lint,
+                                ),
+                            },
+                            XrayObject::Pod(pod) => Apps::Container {
+                                app: container_app::app::App::for_pod(
+                                    &pod.name,
+                                    node.containers(),
+                                    lint,
+                                ),
+                            },
+                            XrayObject::Container(_) => {
+                                let parent = &path[..path.len() - 1];
+                                let Some(pod) = XrayNode::at(&xray_app.roots, parent) else {
+                                    continue;
+                                };
+                                let mut container_app = container_app::app::App::for_pod(
+                                    pod.object.name(),
+                                    pod.containers(),
+                                    lint,
+                                );
+                                container_app.table.state.select(path.last().copied());
+                                Apps::Container { app: container_app }
+                            }
+                        };
+                        history.push(Rc::new(app_holder.clone())); // Save current state
+                        app_holder = new_app_holder;
+                    }
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
                         }
                     }
+                    _ => {}
                 }
             }
+            Apps::Cert { app: cert_app } => {
+                use KeyCode::{Char, Down, Esc, Up};
+                match key.code {
+                    Char('q') => return Ok(()),
+                    Char('j') | Down => cert_app.table.next(wraparound),
+                    Char('k') | Up => cert_app.table.previous(wraparound),
+                    Char('c' | 'C') => cert_app.table.next_color(),
+                    Char(':') => prompt = Some(Prompt::new(PromptKind::Command, String::new())),
+                    Esc => {
+                        if let Some(previous_app) = history.pop() {
+                            app_holder = (*previous_app).clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// The column chooser of the current view, if it is a [`table_view::TableView`].
+fn column_chooser(app_holder: &mut Apps) -> Option<ColumnChooser> {
+    with_table!(app_holder, table => table.column_chooser())
+}
+
+/// Apply `action` to the current view; `None` when its rows cannot be sorted.
+fn sort_table(app_holder: &mut Apps, action: SortAction) -> Option<Result<(), String>> {
+    with_table!(app_holder, table => table.apply_sort(action))
+}
+
+/// Whether the current view takes the paging keys and counts.
+fn navigable(app_holder: &Apps) -> bool {
+    !matches!(app_holder, Apps::Overview { .. })
+}
+
+/// Why the current view ignores `key`, for the keys the table views take
+/// but the other views have no use for.
+fn unsupported(app_holder: &Apps, key: KeyCode) -> Option<&'static str> {
+    if !matches!(
+        app_holder,
+        Apps::Resource { .. } | Apps::Yaml { .. } | Apps::Xray { .. }
+    ) {
+        return None;
+    }
+    match key {
+        KeyCode::Char('/' | 'n' | 'N' | 'f' | 'w' | '<' | '>') => Some("search not supported here"),
+        // Space expands and collapses the xray tree.
+        KeyCode::Char(' ') if !matches!(app_holder, Apps::Xray { .. }) => {
+            Some("marking not supported here")
+        }
+        _ => None,
+    }
+}
+
+/// Move the selection of the current view; `None` for the overview, whose
+/// tiles only step with `j`/`k`.
+fn move_table(app_holder: &mut Apps, motion: Motion, count: Option<usize>) -> Option<()> {
+    if let Some(()) =
+        with_table!(app_holder, table => table.apply_motion(motion, count, table.viewport))
+    {
+        return Some(());
+    }
+    match app_holder {
+        Apps::Resource { app } => app.apply_motion(motion, count, app.viewport),
+        Apps::Yaml { app } => app.apply_motion(motion, count, app.viewport),
+        Apps::Xray { app } => app.apply_motion(motion, count, app.viewport),
+        _ => return None,
+    }
+    Some(())
+}
+
+/// Apply `action` to the current view; `None` when it cannot be searched.
+fn search_table(app_holder: &mut Apps, action: SearchAction) -> Option<bool> {
+    with_table!(app_holder, table => table.apply_search(action))
+}
+
+/// The filter asked for by the prompt of `kind`.
+fn filter_action(kind: PromptKind, input: &str) -> FilterAction {
+    match kind {
+        PromptKind::Query => FilterAction::Query(input.to_string()),
+        _ => FilterAction::Fuzzy(Filter::new(input)),
+    }
+}
+
+/// Apply `action` to the current view; `None` when it cannot be filtered.
+fn filter_table(app_holder: &mut Apps, action: FilterAction) -> Option<Result<(), QueryError>> {
+    with_table!(app_holder, table => table.apply_filter(action))
+}
+
+/// The current filter of the view as the prompt of `kind` takes it.
+fn filter_input(app_holder: &mut Apps, kind: PromptKind) -> Option<String> {
+    fn input(table: &impl FilteredTable, kind: PromptKind) -> String {
+        match kind {
+            PromptKind::Query => table.get_query().map(|query| query.text.clone()),
+            _ => table.get_filter().map(|filter| filter.pattern.clone()),
         }
+        .unwrap_or_default()
+    }
+    with_table!(app_holder, table => input(table, kind))
+}
+
+/// Apply `action` to the marks of the current view; `None` when it has
+/// none.
+fn mark_table(app_holder: &mut Apps, action: MarkAction) -> Option<bool> {
+    with_table!(app_holder, table => table.apply_mark(action))
+}
+
+/// Apply `action` to the marked rows of the current view; `None` when it
+/// has no rows to mark.
+fn bulk_table(app_holder: &mut Apps, action: BulkAction) -> Option<Result<(), MarksError>> {
+    with_table!(app_holder, table => table.apply_bulk(action))
+}
+
+/// How many rows of the current view a bulk action would apply to.
+fn marked_count(app_holder: &mut Apps) -> Option<usize> {
+    with_table!(app_holder, table => table.marked_rows().len())
+}
+
+/// The sort order of the current view as the sort prompt takes it.
+fn sort_order(app_holder: &mut Apps) -> Option<String> {
+    with_table!(app_holder, table => table.sort_order())
+}
+
+fn selector_prompt(selector: Option<&LabelSelector>) -> Prompt {
+    let input = selector.map(ToString::to_string).unwrap_or_default();
+    Prompt::new(PromptKind::Selector, input)
+}
+
+/// Apply submitted prompt input to the current view. An `Err` keeps the prompt
+/// open with the message shown inline so the user can correct the input.
+fn submit_prompt(
+    app_holder: &mut Apps,
+    history: &mut Vec<Rc<Apps>>,
+    discovery: &Result<Discovery, DiscoveryError>,
+    secrets: &dyn SecretSource,
+    lint: &LintConfig,
+    kind: PromptKind,
+    input: &str,
+) -> Result<(), String> {
+    match kind {
+        PromptKind::Selector => {
+            let selector = if input.is_empty() {
+                None
+            } else {
+                Some(LabelSelector::parse(input).map_err(|e| e.to_string())?)
+            };
+            match app_holder {
+                Apps::Rs { app } => app.table.set_selector(selector),
+                Apps::Pod { app } => app.table.set_selector(selector),
+                Apps::Overview { .. }
+                | Apps::Container { .. }
+                | Apps::Event { .. }
+                | Apps::Node { .. }
+                | Apps::Service { .. }
+                | Apps::Ingress { .. }
+                | Apps::Config { .. }
+                | Apps::ConfigEntry { .. }
+                | Apps::CronJob { .. }
+                | Apps::Job { .. }
+                | Apps::Hpa { .. }
+                | Apps::ApiResource { .. }
+                | Apps::Resource { .. }
+                | Apps::Yaml { .. }
+                | Apps::Xray { .. }
+                | Apps::Cert { .. } => {}
+            }
+        }
+        PromptKind::Filter | PromptKind::Query => {
+            if let Some(result) = filter_table(app_holder, filter_action(kind, input)) {
+                result.map_err(|err| err.to_string())?;
+            }
+        }
+        PromptKind::Export => {
+            if let Some(result) = bulk_table(app_holder, BulkAction::Export(input.to_string())) {
+                result.map_err(|err| err.to_string())?;
+            }
+        }
+        PromptKind::Delete(_) => {
+            if matches!(input, "y" | "Y" | "yes") {
+                let _ = bulk_table(app_holder, BulkAction::Delete);
+            }
+        }
+        PromptKind::Search => {
+            search_table(app_holder, SearchAction::Update(Search::new(input)));
+        }
+        PromptKind::Sort => {
+            sort_table(app_holder, SortAction::Prompt(input.to_string())).unwrap_or(Ok(()))?;
+        }
+        PromptKind::Command => {
+            let new_app_holder = command_app(input, discovery, secrets, lint)
+                .ok_or_else(|| format!("unknown resource '{input}'"))?;
+            history.push(Rc::new(app_holder.clone())); // Save current state
+            *app_holder = new_app_holder;
+        }
+    }
+    Ok(())
+}
+
+/// Top level views reachable from the `:` prompt, by kubectl name or short name.
+/// Kinds without a dedicated view fall back to the discovery driven one.
+fn command_app(
+    name: &str,
+    discovery: &Result<Discovery, DiscoveryError>,
+    secrets: &dyn SecretSource,
+    lint: &LintConfig,
+) -> Option<Apps> {
+    match name {
+        "overview" => Some(Apps::Overview {
+            app: overview_app::app::App::new(),
+        }),
+        "xray" => Some(Apps::Xray {
+            app: xray_app::app::App::new(),
+        }),
+        "rs" | "replicasets" => Some(Apps::Rs {
+            app: rs_app::app::App::new(),
+        }),
+        "po" | "pods" => Some(Apps::Pod {
+            app: pod_app::app::App::new(lint),
+        }),
+        "no" | "nodes" => Some(Apps::Node {
+            app: node_app::app::App::new(),
+        }),
+        "svc" | "services" => Some(Apps::Service {
+            app: service_app::app::App::new(),
+        }),
+        "ing" | "ingresses" => Some(Apps::Ingress {
+            app: ingress_app::app::App::new(),
+        }),
+        "cm" | "configmaps" => Some(Apps::Config {
+            app: config_app::app::App::new(false),
+        }),
+        "secret" | "secrets" => Some(Apps::Config {
+            app: config_app::app::App::new(true),
+        }),
+        "tls" => Some(Apps::Cert {
+            app: cert_app::app::App::new(secrets),
+        }),
+        "cj" | "cronjobs" => Some(Apps::CronJob {
+            app: cronjob_app::app::App::new(),
+        }),
+        "job" | "jobs" => Some(Apps::Job {
+            app: job_app::app::App::new(
+                "jobs".to_string(),
+                generate_cronjob_recs()
+                    .into_iter()
+                    .flat_map(|cronjob| cronjob.jobs)
+                    .collect(),
+            ),
+        }),
+        "hpa" | "horizontalpodautoscalers" => Some(Apps::Hpa {
+            app: hpa_app::app::App::new(),
+        }),
+        "ev" | "events" => Some(Apps::Event {
+            app: event_app::app::App::new(),
+        }),
+        "api-resources" => Some(Apps::ApiResource {
+            app: api_resource_app::app::App::new(discovery),
+        }),
+        _ => discovery
+            .iter()
+            .flat_map(|discovery| &discovery.resources)
+            .find(|resource| resource.matches(name))
+            .map(|resource| Apps::Resource {
+                app: resource_app::app::App::new(resource.clone()),
+            }),
     }
 }
@@ -0,0 +1,21 @@
+use crate::tui::data::{generate_node_recs, Node};
+use crate::tui::table_app::TableApp;
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) table: TableApp<Node>,
+    pub(crate) scope: Option<String>,
+}
+
+impl App {
+    pub fn new() -> Self {
+        Self::with_nodes(generate_node_recs(), None)
+    }
+
+    pub fn with_nodes(data_vec: Vec<Node>, scope: Option<String>) -> Self {
+        Self {
+            table: TableApp::new(data_vec, 1),
+            scope,
+        }
+    }
+}
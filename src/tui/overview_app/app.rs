@@ -0,0 +1,142 @@
+use crate::tui::data::{
+    generate_event_recs, generate_node_recs, generate_pod_recs, ClusterSummary, Event, Health,
+    Node, Pod,
+};
+use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
+use crate::tui::table_ui::TuiTableState;
+use itertools::Itertools;
+use ratatui::widgets::{ScrollbarState, TableState};
+
+/// The overview tiles, in the order j/k walk them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Nodes,
+    Headroom,
+    Pods,
+    Warnings,
+    Restarts,
+}
+
+const TILES: [Tile; 5] = [
+    Tile::Nodes,
+    Tile::Headroom,
+    Tile::Pods,
+    Tile::Warnings,
+    Tile::Restarts,
+];
+
+/// Cluster overview: the selected "row" is the focused tile, and the
+/// nodes, pods and events behind the tiles are kept so the views they open
+/// show the same rows the tiles counted.
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) state: TableState,
+    pub(crate) items: Vec<Tile>,
+    pub(crate) nodes: Vec<Node>,
+    pub(crate) pods: Vec<Pod>,
+    pub(crate) events: Vec<Event>,
+    pub(crate) summary: ClusterSummary,
+    pub(crate) scroll_state: ScrollbarState,
+    pub(crate) colors: TableColors,
+    color_index: usize,
+}
+
+impl TuiTableState for App {
+    type Item = Tile;
+
+    fn get_items(&self) -> &[Self::Item] {
+        &self.items
+    }
+
+    fn get_state(&mut self) -> &mut TableState {
+        &mut self.state
+    }
+
+    fn get_scroll_state(&self) -> &ScrollbarState {
+        &self.scroll_state
+    }
+
+    fn set_scroll_state(&mut self, scroll_state: ScrollbarState) {
+        self.scroll_state = scroll_state;
+    }
+
+    fn get_table_colors(&self) -> &TableColors {
+        &self.colors
+    }
+
+    fn set_table_colors(&mut self, colors: TableColors) {
+        self.colors = colors;
+    }
+
+    fn get_color_index(&self) -> usize {
+        self.color_index
+    }
+
+    fn set_color_index(&mut self, color_index: usize) {
+        self.color_index = color_index;
+    }
+}
+
+impl App {
+    pub fn new() -> Self {
+        let nodes = generate_node_recs();
+        let pods = generate_pod_recs();
+        let events = generate_event_recs(None);
+        Self {
+            state: TableState::default().with_selected(0),
+            items: TILES.to_vec(),
+            summary: ClusterSummary::new(&nodes, &pods, &events),
+            scroll_state: ScrollbarState::new((TILES.len() - 1) * ITEM_HEIGHT),
+            colors: TableColors::new(&PALETTES[0]),
+            color_index: 0,
+            nodes,
+            pods,
+            events,
+        }
+    }
+
+    pub fn selected_tile(&self) -> Option<Tile> {
+        self.state
+            .selected()
+            .and_then(|i| self.items.get(i).copied())
+    }
+
+    pub fn not_ready_nodes(&self) -> Vec<Node> {
+        self.nodes
+            .iter()
+            .filter(|node| node.health() != Health::Healthy)
+            .cloned()
+            .collect()
+    }
+
+    /// Busiest nodes first, by share of allocatable CPU in use.
+    pub fn nodes_by_cpu(&self) -> Vec<Node> {
+        self.nodes
+            .iter()
+            .sorted_by_key(|node| {
+                let (used, allocatable) = node.cpu_usage().unwrap_or_default();
+                std::cmp::Reverse(used * 1_000 / allocatable.max(1))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Pods that are neither running nor completed.
+    pub fn problem_pods(&self) -> Vec<Pod> {
+        self.pods
+            .iter()
+            .filter(|pod| !matches!(pod.status(), "Running" | "Completed"))
+            .cloned()
+            .collect()
+    }
+
+    /// Pods that restarted at least once, most restarts first.
+    pub fn restarting_pods(&self) -> Vec<Pod> {
+        self.pods
+            .iter()
+            .filter(|pod| pod.restart_count() > 0)
+            .sorted_by_key(|pod| std::cmp::Reverse(pod.restart_count()))
+            .cloned()
+            .collect()
+    }
+}
@@ -0,0 +1,198 @@
+use crate::tui::overview_app::app::{App, Tile};
+use crate::tui::prompt::Prompt;
+use crate::tui::table_ui::{render_footer, TuiTableState};
+use ratatui::{
+    prelude::*,
+    widgets::{BarChart, Block, BorderType, Borders, Gauge, Paragraph, Sparkline},
+};
+
+pub fn ui(f: &mut Frame, app: &mut App, prompt: Option<&Prompt>) {
+    let rects = Layout::vertical([
+        Constraint::Length(6),
+        Constraint::Min(8),
+        Constraint::Length(3),
+    ])
+    .split(f.size());
+    let top = Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(rects[0]);
+    let bottom = Layout::horizontal([
+        Constraint::Percentage(35),
+        Constraint::Percentage(30),
+        Constraint::Percentage(35),
+    ])
+    .split(rects[1]);
+
+    app.set_colors();
+    f.render_widget(Block::new().bg(app.colors.buffer_bg), f.size());
+
+    render_nodes(f, app, top[0]);
+    render_headroom(f, app, top[1]);
+    render_pods(f, app, bottom[0]);
+    render_warnings(f, app, bottom[1]);
+    render_restarts(f, app, bottom[2]);
+
+    let status = "cluster overview | (Enter) open tile | (r) replicasets".to_string();
+    render_footer(f, app.get_table_colors(), rects[2], prompt, Some(status));
+}
+
+/// The focused tile gets a thick border in the accent color.
+fn tile_block(app: &App, tile: Tile, title: String) -> Block<'static> {
+    let (border_type, border_color) = if app.selected_tile() == Some(tile) {
+        (BorderType::Thick, app.colors.selected_style_fg)
+    } else {
+        (BorderType::Rounded, app.colors.header_bg)
+    };
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(border_type)
+        .border_style(Style::new().fg(border_color))
+        .title(title)
+        .fg(app.colors.row_fg)
+}
+
+/// Accent color up to 70% used, amber up to 90%, red beyond.
+fn usage_color(app: &App, ratio: f64) -> Color {
+    if ratio >= 0.9 {
+        app.colors.near_limit_fg
+    } else if ratio >= 0.7 {
+        app.colors.over_request_fg
+    } else {
+        app.colors.selected_style_fg
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn ratio(used: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        (used as f64 / total as f64).clamp(0.0, 1.0)
+    }
+}
+
+fn render_nodes(f: &mut Frame, app: &App, area: Rect) {
+    let summary = &app.summary;
+    let ready = ratio(summary.nodes_ready as u64, summary.nodes_total as u64);
+    let color = if summary.nodes_ready == summary.nodes_total {
+        app.colors.selected_style_fg
+    } else {
+        app.colors.degraded_row_fg
+    };
+    let gauge = Gauge::default()
+        .block(tile_block(app, Tile::Nodes, " Nodes ready ".to_string()))
+        .gauge_style(Style::new().fg(color).bg(app.colors.alt_row_color))
+        .ratio(ready)
+        .label(format!(
+            "{}/{} ready",
+            summary.nodes_ready, summary.nodes_total
+        ));
+    f.render_widget(gauge, area);
+}
+
+fn render_headroom(f: &mut Frame, app: &App, area: Rect) {
+    let block = tile_block(app, Tile::Headroom, " Headroom ".to_string());
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let (cpu_used, cpu_total) = app.summary.cpu;
+    let (memory_used, memory_total) = app.summary.memory;
+    let rows = Layout::vertical([Constraint::Length(2), Constraint::Length(2)]).split(inner);
+    for (rect, used, total, unit, name) in [
+        (rows[0], cpu_used, cpu_total, "m", "CPU"),
+        (rows[1], memory_used, memory_total, "Gi", "Memory"),
+    ] {
+        let used_ratio = ratio(used, total);
+        let gauge = Gauge::default()
+            .gauge_style(
+                Style::new()
+                    .fg(usage_color(app, used_ratio))
+                    .bg(app.colors.alt_row_color),
+            )
+            .ratio(used_ratio)
+            .label(format!(
+                "{name} {used}{unit}/{total}{unit} used, {}{unit} free",
+                total.saturating_sub(used)
+            ));
+        f.render_widget(gauge, rect.inner(&Margin::new(1, 0)));
+    }
+}
+
+fn render_pods(f: &mut Frame, app: &App, area: Rect) {
+    let total: u64 = app.summary.phases.iter().map(|(_, count)| count).sum();
+    let data = app
+        .summary
+        .phases
+        .iter()
+        .map(|(phase, count)| {
+            let label = match phase.as_str() {
+                "CrashLoopBackOff" => "Crash",
+                "Terminating" => "Term",
+                "Completed" => "Done",
+                other => other,
+            };
+            (label, *count)
+        })
+        .collect::<Vec<_>>();
+    let chart = BarChart::default()
+        .block(tile_block(
+            app,
+            Tile::Pods,
+            format!(" Pods by phase ({total}) "),
+        ))
+        .data(&data)
+        .bar_width(7)
+        .bar_gap(1)
+        .bar_style(Style::new().fg(app.colors.selected_style_fg))
+        .value_style(
+            Style::new()
+                .fg(app.colors.buffer_bg)
+                .bg(app.colors.selected_style_fg),
+        )
+        .label_style(Style::new().fg(app.colors.row_fg));
+    f.render_widget(chart, area);
+}
+
+fn render_warnings(f: &mut Frame, app: &App, area: Rect) {
+    let summary = &app.summary;
+    let block = tile_block(app, Tile::Warnings, " Warning events ".to_string());
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let rows = Layout::vertical([Constraint::Length(2), Constraint::Min(1)]).split(inner);
+    let today: u64 = summary.warnings_per_hour.iter().sum();
+    let color = if summary.warnings_last_hour > 0 {
+        app.colors.warning_row_fg
+    } else {
+        app.colors.row_fg
+    };
+    let counts = Paragraph::new(vec![
+        Line::styled(
+            format!("{} in the last hour", summary.warnings_last_hour),
+            Style::new().fg(color),
+        ),
+        Line::from(format!("{today} in the last 24h")),
+    ]);
+    f.render_widget(counts, rows[0]);
+    let sparkline = Sparkline::default()
+        .data(&summary.warnings_per_hour)
+        .style(Style::new().fg(app.colors.warning_row_fg));
+    f.render_widget(sparkline, rows[1]);
+}
+
+fn render_restarts(f: &mut Frame, app: &App, area: Rect) {
+    let lines = if app.summary.top_restarts.is_empty() {
+        vec![Line::from("no restarts")]
+    } else {
+        app.summary
+            .top_restarts
+            .iter()
+            .map(|(pod, restarts)| Line::from(format!("{restarts:>4}  {pod}")))
+            .collect()
+    };
+    let restarts = Paragraph::new(lines).block(tile_block(
+        app,
+        Tile::Restarts,
+        " Top restarting pods ".to_string(),
+    ));
+    f.render_widget(restarts, area);
+}
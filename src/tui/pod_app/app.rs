@@ -1,64 +1,90 @@
-use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
-use ratatui::widgets::{ScrollbarState, TableState};
-use crate::tui::data::{generate_pod_recs, Pod, pod_constraint_len_calculator};
-use crate::tui::table_ui::TuiTableState;
+use crate::tui::data::{
+    generate_job_pod_recs, generate_node_pod_recs, generate_pod_recs, Job, Pod, Service,
+};
+use crate::tui::lint::LintConfig;
+use crate::tui::metrics::MetricsSource;
+use crate::tui::selector::{LabelSelector, SelectorError};
+use crate::tui::table_app::TableApp;
+
+/// The columns that change with every sample.
+const METRICS_COLUMNS: [usize; 2] = [Pod::CPU, Pod::MEMORY];
 
 #[derive(Clone, Debug)]
 pub struct App {
-    pub(crate) state: TableState,
-    pub(crate) items: Vec<Pod>,
-    pub(crate) longest_item_lens: (u16, u16, u16, u16),
-    pub(crate) scroll_state: ScrollbarState,
-    pub(crate) colors: TableColors,
-    color_index: usize,
+    pub(crate) table: TableApp<Pod>,
+    pub(crate) scope: Option<String>,
+    pub(crate) show_lint: bool,
+    /// The rules the Lint column counts.
+    pub(crate) lint: LintConfig,
 }
 
-impl TuiTableState for App {
-    type Item = Pod;
-
-    fn get_items(&self) -> &[Self::Item] {
-        &self.items
-    }
-
-    fn get_state(&mut self) -> &mut TableState {
-        &mut self.state
+impl App {
+    pub fn new(lint: &LintConfig) -> Self {
+        Self::with_pods(generate_pod_recs(), None, lint)
     }
 
-    fn get_scroll_state(&self) -> &ScrollbarState {
-        &self.scroll_state
+    /// The pods scheduled on `node`.
+    pub fn for_node(node: String, lint: &LintConfig) -> Self {
+        let scope = format!("node: {node}");
+        Self::with_pods(generate_node_pod_recs(&node), Some(scope), lint)
     }
 
-    fn set_scroll_state(&mut self, scroll_state: ScrollbarState) {
-        self.scroll_state = scroll_state;
-    }
-    fn get_table_colors(&self) -> &TableColors {
-        &self.colors
+    /// The pods a Job ran.
+    pub fn for_job(job: &Job, lint: &LintConfig) -> Self {
+        Self::with_pods(
+            generate_job_pod_recs(job),
+            Some(format!("job: {}", job.name)),
+            lint,
+        )
     }
 
-    fn set_table_colors(&mut self, colors: TableColors) {
-        self.colors = colors;
+    /// The pods matched by the selector of `service`, with the ready ones
+    /// listed as its endpoints.
+    pub fn for_service(service: &Service, lint: &LintConfig) -> Result<Self, SelectorError> {
+        let mut app = Self::new(lint);
+        let port = service.target_port().unwrap_or_default();
+        app.table
+            .update_rows(|pod| pod.port = Some(port.to_string()));
+        app.table
+            .set_selector(Some(LabelSelector::parse(&service.selector)?));
+        app.scope = Some(format!("service: {}", service.name));
+        Ok(app)
     }
 
-    fn get_color_index(&self) -> usize {
-        self.color_index
+    /// The endpoints among the pods shown, when they are a service's.
+    pub fn endpoints_status(&self) -> Option<String> {
+        if !self.table.all_items.iter().any(|pod| pod.port.is_some()) {
+            return None;
+        }
+        let endpoints = self
+            .table
+            .items
+            .iter()
+            .filter_map(Pod::endpoint)
+            .collect::<Vec<_>>();
+        Some(match endpoints.len() {
+            0 => "no endpoints".to_string(),
+            1..=3 => format!("endpoints: {}", endpoints.join(", ")),
+            n => format!("endpoints: {}, +{} more", endpoints[..3].join(", "), n - 3),
+        })
     }
 
-    fn set_color_index(&mut self, color_index: usize) {
-        self.color_index = color_index;
-    }
-}
-impl App {
-    pub fn new() -> Self {
-        let data_vec = generate_pod_recs();
+    /// `data_vec` linted with `lint`.
+    pub fn with_pods(mut data_vec: Vec<Pod>, scope: Option<String>, lint: &LintConfig) -> Self {
+        for pod in &mut data_vec {
+            pod.lint(lint);
+        }
         Self {
-            state: TableState::default().with_selected(0),
-            longest_item_lens: pod_constraint_len_calculator(&data_vec),
-            scroll_state: ScrollbarState::new((data_vec.len() - 1) * ITEM_HEIGHT),
-            colors: TableColors::new(&PALETTES[0]),
-            color_index: 1,
-            items: data_vec,
+            table: TableApp::new(data_vec, 1),
+            scope,
+            show_lint: false,
+            lint: lint.clone(),
         }
     }
 
-
-}
\ No newline at end of file
+    /// Take the next usage sample of every pod, keeping the selection.
+    pub fn sample_metrics(&mut self, source: &mut impl MetricsSource) {
+        self.table.update_rows(|pod| pod.record_metrics(source));
+        self.table.resort_after(&METRICS_COLUMNS);
+    }
+}
@@ -1,101 +1,31 @@
 use crate::tui::pod_app::app::App;
-use crate::tui::style::INFO_TEXT;
-use ratatui::{
-    prelude::*,
-    widgets::{
-        Block, BorderType, Borders, Cell, HighlightSpacing, Paragraph, Row, Scrollbar,
-        ScrollbarOrientation, Table,
-    },
-};
-use crate::tui::table_ui::TuiTableState;
+use crate::tui::prompt::Prompt;
+use crate::tui::table_ui::{render_footer, render_lint_popup, TuiTableState};
+use crate::tui::table_view::TableRow;
+use ratatui::prelude::*;
 
-pub fn ui(f: &mut Frame, app: &mut App) {
+pub fn ui(f: &mut Frame, app: &mut App, prompt: Option<&Prompt>) {
     let rects = Layout::vertical([Constraint::Min(5), Constraint::Length(3)]).split(f.size());
 
-    app.set_colors();
-
-    render_table(f, app, rects[0]);
-
-    render_scrollbar(f, app, rects[0]);
-
-    render_footer(f, app, rects[1]);
-}
-
-fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
-    let header_style = Style::default()
-        .fg(app.colors.header_fg)
-        .bg(app.colors.header_bg);
-    let selected_style = Style::default()
-        .add_modifier(Modifier::REVERSED)
-        .fg(app.colors.selected_style_fg);
-
-    let header = ["Podname", "Description", "Age", "C"]
-        .iter()
-        .copied()
-        .map(Cell::from)
-        .collect::<Row>()
-        .style(header_style)
-        .height(1);
-    let rows = app.items.iter().enumerate().map(|(i, data)| {
-        let color = match i % 2 {
-            0 => app.colors.normal_row_color,
-            _ => app.colors.alt_row_color,
-        };
-        let item = data.ref_array();
-        item.iter()
-            .copied()
-            .map(|content| Cell::from(Text::from(format!("\n{content}\n"))))
-            .collect::<Row>()
-            .style(Style::new().fg(app.colors.row_fg).bg(color))
-            .height(4)
-    });
-    let bar = " █ ";
-    let t = Table::new(
-        rows,
-        [
-            // + 1 is for padding.
-            Constraint::Length(app.longest_item_lens.0 + 2),
-            Constraint::Min(app.longest_item_lens.1 + 2),
-            Constraint::Min(app.longest_item_lens.2 + 2),
-            Constraint::Min(app.longest_item_lens.3 + 2),
-        ],
-    )
-    .header(header)
-    .highlight_style(selected_style)
-    .highlight_symbol(Text::from(vec![
-        "".into(),
-        bar.into(),
-        bar.into(),
-        "".into(),
-    ]))
-    .bg(app.colors.buffer_bg)
-    .highlight_spacing(HighlightSpacing::Always);
-    f.render_stateful_widget(t, area, &mut app.state);
-}
-
-fn render_scrollbar(f: &mut Frame, app: &mut App, area: Rect) {
-    f.render_stateful_widget(
-        Scrollbar::default()
-            .orientation(ScrollbarOrientation::VerticalRight)
-            .begin_symbol(None)
-            .end_symbol(None),
-        area.inner(&Margin {
-            vertical: 1,
-            horizontal: 1,
-        }),
-        &mut app.scroll_state,
-    );
-}
-
-fn render_footer(f: &mut Frame, app: &App, area: Rect) {
-    let info_footer = Paragraph::new(Line::from(INFO_TEXT))
-        .style(Style::new().fg(app.colors.row_fg).bg(app.colors.buffer_bg))
-        .alignment(Alignment::Center)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::new().fg(app.colors.footer_border_color))
-                .border_type(BorderType::Double),
-        );
-    f.render_widget(info_footer, area);
+    let endpoints = app.endpoints_status();
+    let table = &mut app.table;
+    table.set_colors();
+    table.render(f, rects[0]);
+
+    let status = [
+        app.scope.clone(),
+        endpoints,
+        table.selector.as_ref().map(|s| format!("selector: {s}")),
+    ]
+    .into_iter()
+    .flatten()
+    .reduce(|a, b| format!("{a} | {b}"));
+    let status = table.status(status);
+    render_footer(f, table.get_table_colors(), rects[1], prompt, status);
+
+    if app.show_lint {
+        if let Some(item) = table.state.selected().and_then(|i| table.items.get(i)) {
+            render_lint_popup(f, &table.colors, &item.key(), &item.findings, &app.lint);
+        }
+    }
 }
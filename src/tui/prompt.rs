@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PromptKind {
+    Selector,
+    Command,
+    Sort,
+    Search,
+    Filter,
+    Query,
+    Export,
+    /// Confirm deleting this many rows.
+    Delete(usize),
+}
+
+impl PromptKind {
+    pub fn prefix(self) -> Cow<'static, str> {
+        match self {
+            Self::Selector => "selector: ".into(),
+            Self::Command => ":".into(),
+            Self::Sort => "sort: ".into(),
+            Self::Search => "/".into(),
+            Self::Filter => "filter: ".into(),
+            Self::Query => "where: ".into(),
+            Self::Export => "export to (empty for <kind>.csv): ".into(),
+            Self::Delete(1) => "delete the row? (y/n) ".into(),
+            Self::Delete(count) => format!("delete {count} rows? (y/n) ").into(),
+        }
+    }
+}
+
+pub enum PromptAction {
+    Continue,
+    Submit(String),
+    Cancel,
+}
+
+/// A single line input shown in the footer while the user types a query.
+#[derive(Clone, Debug)]
+pub struct Prompt {
+    pub(crate) kind: PromptKind,
+    pub(crate) input: String,
+    /// The input the prompt opened with, restored by live prompts on Esc.
+    pub(crate) initial: String,
+    pub(crate) error: Option<String>,
+}
+
+impl Prompt {
+    pub fn new(kind: PromptKind, input: String) -> Self {
+        Self {
+            kind,
+            initial: input.clone(),
+            input,
+            error: None,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> PromptAction {
+        match key.code {
+            KeyCode::Enter => PromptAction::Submit(self.input.trim().to_string()),
+            KeyCode::Esc => PromptAction::Cancel,
+            KeyCode::Backspace => {
+                self.input.pop();
+                self.error = None;
+                PromptAction::Continue
+            }
+            KeyCode::Char(c) => {
+                self.input.push(c);
+                self.error = None;
+                PromptAction::Continue
+            }
+            _ => PromptAction::Continue,
+        }
+    }
+
+    pub fn text(&self) -> String {
+        format!("{}{}█", self.kind.prefix(), self.input)
+    }
+}
@@ -0,0 +1,510 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+use regex::{Regex, RegexBuilder};
+use thiserror::Error;
+
+use crate::tui::sort::{leading_age, leading_number, natural_cmp};
+use crate::tui::table_view::TableRow;
+
+/// Why a filter expression could not be parsed; positions count characters
+/// from 1.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum QueryError {
+    #[error("unexpected '{found}' at {at}")]
+    UnexpectedChar { found: char, at: usize },
+    #[error("unterminated {what} starting at {at}")]
+    Unterminated { what: &'static str, at: usize },
+    #[error("expected {expected} at {at}, found '{found}'")]
+    Expected {
+        expected: &'static str,
+        found: String,
+        at: usize,
+    },
+    #[error("expected {expected} at end of input")]
+    UnexpectedEnd { expected: &'static str },
+    #[error("unknown field '{name}' at {at}, expected one of: {fields}")]
+    UnknownField {
+        name: String,
+        at: usize,
+        fields: String,
+    },
+    #[error("invalid regex at {at}: {message}")]
+    Regex { at: usize, message: String },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Match,
+    NotMatch,
+}
+
+impl Op {
+    fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Eq => ordering.is_eq(),
+            Self::Ne => ordering.is_ne(),
+            Self::Lt => ordering.is_lt(),
+            Self::Le => ordering.is_le(),
+            Self::Gt => ordering.is_gt(),
+            Self::Ge => ordering.is_ge(),
+            Self::Match | Self::NotMatch => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    /// A field name or a bare value such as `30d` or `Running`.
+    Word(String),
+    /// A quoted value.
+    Text(String),
+    Regex(String),
+    And,
+    Or,
+    Not,
+    Op(Op),
+    Open,
+    Close,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Self::Word(word) => word.clone(),
+            Self::Text(text) => format!("\"{text}\""),
+            Self::Regex(regex) => format!("/{regex}/"),
+            Self::And => "&&".to_string(),
+            Self::Or => "||".to_string(),
+            Self::Not => "!".to_string(),
+            Self::Op(op) => match op {
+                Op::Eq => "==",
+                Op::Ne => "!=",
+                Op::Lt => "<",
+                Op::Le => "<=",
+                Op::Gt => ">",
+                Op::Ge => ">=",
+                Op::Match => "~",
+                Op::NotMatch => "!~",
+            }
+            .to_string(),
+            Self::Open => "(".to_string(),
+            Self::Close => ")".to_string(),
+        }
+    }
+}
+
+const fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-')
+}
+
+/// Tokens of `input` with the position they start at.
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, QueryError> {
+    let chars = input.chars().collect_vec();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let at = i + 1;
+        let next = chars.get(i + 1).copied();
+        let (token, len) = match chars[i] {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => (Token::Open, 1),
+            ')' => (Token::Close, 1),
+            '&' if next == Some('&') => (Token::And, 2),
+            '|' if next == Some('|') => (Token::Or, 2),
+            '=' if next == Some('=') => (Token::Op(Op::Eq), 2),
+            '=' => (Token::Op(Op::Eq), 1),
+            '!' if next == Some('=') => (Token::Op(Op::Ne), 2),
+            '!' if next == Some('~') => (Token::Op(Op::NotMatch), 2),
+            '!' => (Token::Not, 1),
+            '<' if next == Some('=') => (Token::Op(Op::Le), 2),
+            '<' => (Token::Op(Op::Lt), 1),
+            '>' if next == Some('=') => (Token::Op(Op::Ge), 2),
+            '>' => (Token::Op(Op::Gt), 1),
+            '~' => (Token::Op(Op::Match), 1),
+            quote @ ('"' | '\'') => {
+                let len = chars[i + 1..]
+                    .iter()
+                    .position(|&c| c == quote)
+                    .ok_or(QueryError::Unterminated { what: "string", at })?;
+                let text = chars[i + 1..i + 1 + len].iter().collect();
+                (Token::Text(text), len + 2)
+            }
+            '/' => {
+                let mut regex = String::new();
+                let mut end = None;
+                let mut j = i + 1;
+                while j < chars.len() {
+                    match (chars[j], chars.get(j + 1)) {
+                        ('\\', Some('/')) => {
+                            regex.push('/');
+                            j += 2;
+                        }
+                        ('/', _) => {
+                            end = Some(j);
+                            break;
+                        }
+                        (c, _) => {
+                            regex.push(c);
+                            j += 1;
+                        }
+                    }
+                }
+                let end = end.ok_or(QueryError::Unterminated { what: "regex", at })?;
+                (Token::Regex(regex), end + 1 - i)
+            }
+            c if is_word_char(c) => {
+                let len = chars[i..].iter().take_while(|&&c| is_word_char(c)).count();
+                (Token::Word(chars[i..i + len].iter().collect()), len)
+            }
+            found => return Err(QueryError::UnexpectedChar { found, at }),
+        };
+        tokens.push((token, at));
+        i += len;
+    }
+    Ok(tokens)
+}
+
+#[derive(Clone, Debug)]
+enum Operand {
+    /// Index into the cells followed by the extra field values of a row.
+    Field(usize),
+    Value(String),
+}
+
+impl Operand {
+    fn resolve<'a>(&'a self, values: &'a [String]) -> &'a str {
+        match self {
+            Self::Field(i) => values.get(*i).map_or("", String::as_str),
+            Self::Value(value) => value,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Expr {
+    Or(Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Compare(Operand, Op, Operand),
+    Matches(Operand, Regex, bool),
+}
+
+/// `a` against `b` as ages when both are, else as numbers when both start
+/// with one, else as text ignoring case.
+fn compare_values(a: &str, b: &str) -> Ordering {
+    if let (Some(a), Some(b)) = (leading_age(a), leading_age(b)) {
+        return a.total_cmp(&b);
+    }
+    if let (Some(a), Some(b)) = (leading_number(a), leading_number(b)) {
+        return a.total_cmp(&b);
+    }
+    natural_cmp(a, b)
+}
+
+impl Expr {
+    fn eval(&self, values: &[String]) -> bool {
+        match self {
+            Self::Or(a, b) => a.eval(values) || b.eval(values),
+            Self::And(a, b) => a.eval(values) && b.eval(values),
+            Self::Not(a) => !a.eval(values),
+            Self::Compare(a, op, b) => {
+                op.holds(compare_values(a.resolve(values), b.resolve(values)))
+            }
+            Self::Matches(a, regex, negate) => regex.is_match(a.resolve(values)) != *negate,
+        }
+    }
+}
+
+/// The names a filter expression can use for the values of `T`, with the
+/// index of their value: each column title in snake case, `name` for the
+/// first column, then [`TableRow::FIELDS`].
+pub fn field_names<T: TableRow>() -> Vec<(String, usize)> {
+    let columns = T::COLUMNS.iter().map(|column| {
+        column
+            .title
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .join("_")
+            .to_lowercase()
+    });
+    let mut names = columns.enumerate().map(|(i, name)| (name, i)).collect_vec();
+    if !names.iter().any(|(name, _)| name == "name") {
+        names.push(("name".to_string(), 0));
+    }
+    let extra = T::FIELDS.iter().enumerate();
+    names.extend(extra.map(|(i, name)| (name.to_string(), T::COLUMNS.len() + i)));
+    names
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    /// Field names and the index of their value.
+    fields: Vec<(String, usize)>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn next(&mut self, expected: &'static str) -> Result<(Token, usize), QueryError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or(QueryError::UnexpectedEnd { expected })?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn or(&mut self) -> Result<Expr, QueryError> {
+        let mut expr = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, QueryError> {
+        let mut expr = self.unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            expr = Expr::And(Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, QueryError> {
+        match self.peek() {
+            Some(Token::Not) => {
+                self.pos += 1;
+                Ok(Expr::Not(Box::new(self.unary()?)))
+            }
+            Some(Token::Open) => {
+                self.pos += 1;
+                let expr = self.or()?;
+                match self.next("')'")? {
+                    (Token::Close, _) => Ok(expr),
+                    (token, at) => Err(QueryError::Expected {
+                        expected: "')'",
+                        found: token.describe(),
+                        at,
+                    }),
+                }
+            }
+            _ => self.comparison(),
+        }
+    }
+
+    fn comparison(&mut self) -> Result<Expr, QueryError> {
+        let left = self.operand(true)?;
+        let op = match self.next("a comparison")? {
+            (Token::Op(op), _) => op,
+            (token, at) => {
+                return Err(QueryError::Expected {
+                    expected: "a comparison",
+                    found: token.describe(),
+                    at,
+                })
+            }
+        };
+        if !matches!(op, Op::Match | Op::NotMatch) {
+            return Ok(Expr::Compare(left, op, self.operand(false)?));
+        }
+        match self.next("a /regex/")? {
+            (Token::Regex(pattern) | Token::Text(pattern), at) => {
+                let regex = RegexBuilder::new(&pattern)
+                    .case_insensitive(!pattern.chars().any(char::is_uppercase))
+                    .build()
+                    .map_err(|err| QueryError::Regex {
+                        at,
+                        message: err
+                            .to_string()
+                            .lines()
+                            .last()
+                            .unwrap_or_default()
+                            .to_string(),
+                    })?;
+                Ok(Expr::Matches(left, regex, op == Op::NotMatch))
+            }
+            (token, at) => Err(QueryError::Expected {
+                expected: "a /regex/",
+                found: token.describe(),
+                at,
+            }),
+        }
+    }
+
+    /// A field, or a value; a word that names no field is a value on the
+    /// right of a comparison and a mistake on the left unless it is a number.
+    fn operand(&mut self, left: bool) -> Result<Operand, QueryError> {
+        match self.next("a field or value")? {
+            (Token::Word(word), at) => {
+                let field = self
+                    .fields
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(&word));
+                match field {
+                    Some(&(_, i)) => Ok(Operand::Field(i)),
+                    None if !left || word.starts_with(|c: char| c.is_ascii_digit()) => {
+                        Ok(Operand::Value(word))
+                    }
+                    None => Err(QueryError::UnknownField {
+                        name: word,
+                        at,
+                        fields: self.fields.iter().map(|(name, _)| name).join(", "),
+                    }),
+                }
+            }
+            (Token::Text(text), _) => Ok(Operand::Value(text)),
+            (token, at) => Err(QueryError::Expected {
+                expected: "a field or value",
+                found: token.describe(),
+                at,
+            }),
+        }
+    }
+}
+
+/// A filter expression over the fields of a row, e.g.
+/// `age > 30d && ready != desired || name ~ /^api-/`.
+#[derive(Clone, Debug)]
+pub struct Query {
+    pub(crate) text: String,
+    expr: Expr,
+}
+
+impl Query {
+    pub fn parse<T: TableRow>(input: &str) -> Result<Self, QueryError> {
+        let mut parser = Parser {
+            tokens: tokenize(input)?,
+            pos: 0,
+            fields: field_names::<T>(),
+        };
+        let expr = parser.or()?;
+        if let Some((token, at)) = parser.tokens.get(parser.pos) {
+            return Err(QueryError::Expected {
+                expected: "'&&' or '||'",
+                found: token.describe(),
+                at: *at,
+            });
+        }
+        Ok(Self {
+            text: input.to_string(),
+            expr,
+        })
+    }
+
+    pub fn matches<T: TableRow>(&self, item: &T) -> bool {
+        let values = item
+            .cells()
+            .into_iter()
+            .chain(item.field_values())
+            .collect_vec();
+        self.expr.eval(&values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::data::Rs;
+    use crate::tui::query::{field_names, Query, QueryError};
+    use crate::tui::table_view::TestRow;
+
+    fn matching(input: &str, rows: &[TestRow]) -> Vec<&'static str> {
+        let query = Query::parse::<TestRow>(input).unwrap();
+        rows.iter()
+            .filter(|row| query.matches(*row))
+            .map(|row| row.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_field_names() {
+        let names = field_names::<TestRow>()
+            .into_iter()
+            .map(|(name, i)| format!("{name}={i}"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "name=0",
+                "status=1",
+                "last_seen=2",
+                "pods=3",
+                "ready=4",
+                "desired=5"
+            ],
+            names
+        );
+        // Kinds without a "Name" column still match on the key column.
+        assert!(field_names::<Rs>().contains(&("name".to_string(), 0)));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let rows = [
+            TestRow::new("api-7f9c", "Running").seen("200d").pods("4/4"),
+            TestRow::new("api-5d2a", "Running").seen("2d").pods("3/4"),
+            TestRow::new("web-1b3c", "Running").seen("45d").pods("2/4"),
+            TestRow::new("worker-9e8d", "Running")
+                .seen("3h")
+                .pods("1/1"),
+        ];
+        assert_eq!(
+            vec!["api-7f9c", "api-5d2a", "web-1b3c"],
+            matching(
+                "last_seen > 30d && ready != desired || name ~ /^api-/",
+                &rows
+            )
+        );
+        assert_eq!(
+            vec!["web-1b3c"],
+            matching("last_seen >= 30d && !(name ~ /api/)", &rows)
+        );
+        assert_eq!(vec!["worker-9e8d"], matching("last_seen < 1d", &rows));
+        assert!(matching("last_seen > 999999999999999d", &rows).is_empty());
+        assert_eq!(
+            vec!["api-5d2a"],
+            matching("ready == 3 && pods !~ '^4'", &rows)
+        );
+        assert_eq!(vec!["web-1b3c"], matching("name == \"WEB-1b3c\"", &rows));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input| Query::parse::<TestRow>(input).unwrap_err().to_string();
+        assert_eq!("expected a comparison at end of input", error("pods"));
+        assert_eq!(
+            "expected a field or value at 8, found '&&'",
+            error("pods > && name")
+        );
+        assert_eq!(
+            "expected '&&' or '||' at 10, found 'name'",
+            error("pods > 3 name")
+        );
+        assert_eq!("expected ')' at end of input", error("(pods > 3"));
+        assert_eq!("unterminated regex starting at 8", error("name ~ /^api"));
+        assert_eq!("unexpected '$' at 8", error("pods > $5"));
+        assert!(error("name ~ /(/").starts_with("invalid regex at 8"));
+        assert_eq!(
+            Err(QueryError::UnknownField {
+                name: "age".to_string(),
+                at: 1,
+                fields: "name, status, last_seen, pods, ready, desired".to_string()
+            }),
+            Query::parse::<TestRow>("age == 3h").map(|_| ())
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::tui::data::columns_len_calculator;
+use crate::tui::discovery::{generate_resource_objects, ApiResource, PrinterColumn};
+use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
+use crate::tui::table_ui::TuiTableState;
+use chrono::Utc;
+use ratatui::widgets::{ScrollbarState, TableState};
+use serde_json::Value;
+
+/// One object of a discovered kind, with its cells already evaluated.
+#[derive(Clone, Debug)]
+pub struct ResourceRow {
+    pub(crate) cells: Vec<String>,
+    pub(crate) object: Value,
+}
+
+impl AsRef<[String]> for ResourceRow {
+    fn as_ref(&self) -> &[String] {
+        &self.cells
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) state: TableState,
+    pub(crate) items: Vec<ResourceRow>,
+    pub(crate) resource: ApiResource,
+    pub(crate) columns: Vec<PrinterColumn>,
+    pub(crate) longest_item_lens: Vec<u16>,
+    pub(crate) scroll_state: ScrollbarState,
+    pub(crate) colors: TableColors,
+    /// Lines the rows had below the header when last drawn.
+    pub(crate) viewport: u16,
+    color_index: usize,
+}
+
+impl TuiTableState for App {
+    type Item = ResourceRow;
+
+    fn get_items(&self) -> &[Self::Item] {
+        &self.items
+    }
+
+    fn get_state(&mut self) -> &mut TableState {
+        &mut self.state
+    }
+
+    fn get_scroll_state(&self) -> &ScrollbarState {
+        &self.scroll_state
+    }
+
+    fn set_scroll_state(&mut self, scroll_state: ScrollbarState) {
+        self.scroll_state = scroll_state;
+    }
+
+    fn get_table_colors(&self) -> &TableColors {
+        &self.colors
+    }
+
+    fn set_table_colors(&mut self, colors: TableColors) {
+        self.colors = colors;
+    }
+
+    fn get_color_index(&self) -> usize {
+        self.color_index
+    }
+
+    fn set_color_index(&mut self, color_index: usize) {
+        self.color_index = color_index;
+    }
+}
+
+impl App {
+    pub fn new(resource: ApiResource) -> Self {
+        let now = Utc::now();
+        let columns = resource.columns();
+        let data_vec = generate_resource_objects(&resource)
+            .into_iter()
+            .map(|object| ResourceRow {
+                cells: columns.iter().map(|c| c.cell(&object, now)).collect(),
+                object,
+            })
+            .collect::<Vec<_>>();
+        Self {
+            state: TableState::default().with_selected(0),
+            longest_item_lens: columns_len_calculator(
+                &std::iter::once(columns.iter().map(|c| c.name.clone()).collect())
+                    .chain(data_vec.iter().map(|row| row.cells.clone()))
+                    .collect::<Vec<Vec<String>>>(),
+            ),
+            scroll_state: ScrollbarState::new(data_vec.len().saturating_sub(1) * ITEM_HEIGHT),
+            colors: TableColors::new(&PALETTES[0]),
+            viewport: 0,
+            color_index: 2,
+            resource,
+            columns,
+            items: data_vec,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::tui::data::Health;
+use crate::tui::prompt::Prompt;
+use crate::tui::resource_app::app::App;
+use crate::tui::table_ui::{render_footer, TuiTableState};
+use ratatui::{
+    prelude::*,
+    widgets::{Cell, HighlightSpacing, Row, Scrollbar, ScrollbarOrientation, Table},
+};
+
+pub fn ui(f: &mut Frame, app: &mut App, prompt: Option<&Prompt>) {
+    let rects = Layout::vertical([Constraint::Min(5), Constraint::Length(3)]).split(f.size());
+
+    app.set_colors();
+
+    render_table(f, app, rects[0]);
+
+    render_scrollbar(f, app, rects[0]);
+
+    let status = format!(
+        "{} ({}) | (y) yaml",
+        app.resource.name,
+        app.resource.api_version()
+    );
+    render_footer(f, app.get_table_colors(), rects[1], prompt, Some(status));
+}
+
+fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let header_style = Style::default()
+        .fg(app.colors.header_fg)
+        .bg(app.colors.header_bg);
+    let selected_style = Style::default()
+        .add_modifier(Modifier::REVERSED)
+        .fg(app.colors.selected_style_fg);
+
+    let header = app
+        .columns
+        .iter()
+        .map(|c| c.name.as_str())
+        .map(Cell::from)
+        .collect::<Row>()
+        .style(header_style)
+        .height(1);
+    let rows = app.items.iter().enumerate().map(|(i, data)| {
+        data.cells
+            .iter()
+            .map(|content| Cell::from(Text::from(format!("\n{content}\n"))))
+            .collect::<Row>()
+            .style(app.colors.row_style(Health::Healthy, i))
+            .height(4)
+    });
+    let bar = " █ ";
+    // + 1 is for padding.
+    let widths = app.longest_item_lens.iter().enumerate().map(|(i, len)| {
+        if i + 1 == app.longest_item_lens.len() {
+            Constraint::Min(len + 2)
+        } else {
+            Constraint::Length(len + 2)
+        }
+    });
+    let t = Table::new(rows, widths)
+        .header(header)
+        .highlight_style(selected_style)
+        .highlight_symbol(Text::from(vec![
+            "".into(),
+            bar.into(),
+            bar.into(),
+            "".into(),
+        ]))
+        .bg(app.colors.buffer_bg)
+        .highlight_spacing(HighlightSpacing::Always);
+    f.render_stateful_widget(t, area, &mut app.state);
+    app.viewport = area.height.saturating_sub(1);
+}
+
+fn render_scrollbar(f: &mut Frame, app: &mut App, area: Rect) {
+    f.render_stateful_widget(
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None),
+        area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        }),
+        &mut app.scroll_state,
+    );
+}
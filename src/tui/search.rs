@@ -0,0 +1,165 @@
+use std::ops::Range;
+
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use regex::{Regex, RegexBuilder};
+
+use crate::tui::table_ui::TuiTableState;
+use crate::tui::table_view::TableRow;
+
+/// A `/` search: a regex, or the literal text when it is not a valid one.
+/// Case is ignored unless the pattern has an upper case letter.
+#[derive(Clone, Debug)]
+pub struct Search {
+    regex: Regex,
+}
+
+impl Search {
+    /// `None` for an empty pattern.
+    pub fn new(pattern: &str) -> Option<Self> {
+        if pattern.is_empty() {
+            return None;
+        }
+        let ignore_case = !pattern.chars().any(char::is_uppercase);
+        let build = |pattern: &str| {
+            RegexBuilder::new(pattern)
+                .case_insensitive(ignore_case)
+                .build()
+        };
+        let regex = build(pattern)
+            .or_else(|_| build(&regex::escape(pattern)))
+            .ok()?;
+        Some(Self { regex })
+    }
+
+    /// Byte ranges of the non-empty matches in `text`.
+    pub fn find(&self, text: &str) -> Vec<Range<usize>> {
+        self.regex
+            .find_iter(text)
+            .map(|found| found.range())
+            .filter(|range| !range.is_empty())
+            .collect()
+    }
+
+    /// Whether any cell of `item` matches.
+    pub fn matches<T: TableRow>(&self, item: &T) -> bool {
+        item.cells().iter().any(|cell| self.regex.is_match(cell))
+    }
+}
+
+/// `text` with the byte ranges `found` in `style`.
+pub fn highlight(text: String, found: &[Range<usize>], style: Style) -> Line<'static> {
+    if found.is_empty() {
+        return Line::from(text);
+    }
+    let mut spans = Vec::new();
+    let mut end = 0;
+    for range in found {
+        spans.push(Span::raw(text[end..range.start].to_string()));
+        spans.push(Span::styled(text[range.clone()].to_string(), style));
+        end = range.end;
+    }
+    spans.push(Span::raw(text[end..].to_string()));
+    Line::from(spans)
+}
+
+/// A change to the search of a view from the `/` prompt or `n`/`N`.
+pub enum SearchAction {
+    /// The pattern as typed so far.
+    Update(Option<Search>),
+    /// Jump to the next match, or the previous one with `false`.
+    Next(bool),
+}
+
+/// A [`TuiTableState`] whose rows can be searched with `/`, `n` and `N`.
+pub trait SearchTable: TuiTableState<Item: TableRow> {
+    fn get_search(&self) -> Option<&Search>;
+    fn set_search(&mut self, search: Option<Search>);
+
+    /// Search for `search` as it is typed: stay on the selected row while it
+    /// matches, otherwise move to the next row that does.
+    fn search(&mut self, search: Option<Search>) {
+        self.set_search(search);
+        let Some(search) = self.get_search().cloned() else {
+            return;
+        };
+        if !self
+            .selected_item()
+            .is_some_and(|item| search.matches(item))
+        {
+            self.select_match(true, |item| search.matches(item));
+        }
+    }
+
+    /// Jump to the next matching row, or the previous one with `forward`
+    /// false; `false` when no row matches.
+    fn search_next(&mut self, forward: bool) -> bool {
+        let Some(search) = self.get_search().cloned() else {
+            return false;
+        };
+        self.select_match(forward, |item| search.matches(item))
+    }
+
+    /// Apply `action`; `false` when `n`/`N` found no matching row.
+    fn apply_search(&mut self, action: SearchAction) -> bool {
+        match action {
+            SearchAction::Update(search) => {
+                self.search(search);
+                true
+            }
+            SearchAction::Next(forward) => self.search_next(forward),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::search::{highlight, Search};
+    use ratatui::style::{Style, Stylize};
+    use ratatui::text::{Line, Span};
+
+    #[test]
+    fn test_smart_case() {
+        let search = Search::new("running").unwrap();
+        assert_eq!(vec![0..7, 8..15], search.find("Running running"));
+
+        let search = Search::new("Running").unwrap();
+        assert_eq!(vec![0..7], search.find("Running running"));
+        assert!(Search::new("").is_none());
+    }
+
+    #[test]
+    fn test_regex_and_literal() {
+        let search = Search::new("^api-[0-9]+").unwrap();
+        assert_eq!(vec![0..6], search.find("api-12-x"));
+        assert!(search.find("web-api-12").is_empty());
+
+        // Not a valid regex, so matched as typed.
+        let search = Search::new("cpu: 9(").unwrap();
+        assert_eq!(vec![0..7], search.find("cpu: 9(5%)"));
+    }
+
+    #[test]
+    fn test_highlight() {
+        let search = Search::new("web").unwrap();
+        let style = Style::new().reversed();
+        assert_eq!(
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("web", style),
+                Span::raw("-1 "),
+                Span::styled("WEB", style),
+                Span::raw(""),
+            ]),
+            highlight(
+                "  web-1 WEB".to_string(),
+                &search.find("  web-1 WEB"),
+                style
+            )
+        );
+        assert_eq!(
+            Line::from("api"),
+            highlight("api".to_string(), &search.find("api"), style)
+        );
+    }
+}
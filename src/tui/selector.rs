@@ -0,0 +1,250 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use itertools::Itertools;
+use thiserror::Error;
+
+pub type Labels = BTreeMap<String, String>;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SelectorError {
+    #[error("empty requirement at position {0}")]
+    EmptyRequirement(usize),
+    #[error("invalid label key '{0}'")]
+    InvalidKey(String),
+    #[error("invalid label value '{0}'")]
+    InvalidValue(String),
+    #[error("unbalanced parentheses")]
+    UnbalancedParens,
+    #[error("expected a (value, ...) set after '{0}'")]
+    ExpectedSet(String),
+    #[error("unknown operator '{0}'")]
+    UnknownOperator(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Requirement {
+    Equals(String, String),
+    NotEquals(String, String),
+    In(String, Vec<String>),
+    NotIn(String, Vec<String>),
+    Exists(String),
+    DoesNotExist(String),
+}
+
+impl Requirement {
+    pub fn matches(&self, labels: &Labels) -> bool {
+        match self {
+            Self::Equals(k, v) => labels.get(k) == Some(v),
+            Self::NotEquals(k, v) => labels.get(k) != Some(v),
+            Self::In(k, vs) => labels.get(k).is_some_and(|v| vs.contains(v)),
+            Self::NotIn(k, vs) => !labels.get(k).is_some_and(|v| vs.contains(v)),
+            Self::Exists(k) => labels.contains_key(k),
+            Self::DoesNotExist(k) => !labels.contains_key(k),
+        }
+    }
+}
+
+impl fmt::Display for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Equals(k, v) => write!(f, "{k}={v}"),
+            Self::NotEquals(k, v) => write!(f, "{k}!={v}"),
+            Self::In(k, vs) => write!(f, "{k} in ({})", vs.join(",")),
+            Self::NotIn(k, vs) => write!(f, "{k} notin ({})", vs.join(",")),
+            Self::Exists(k) => write!(f, "{k}"),
+            Self::DoesNotExist(k) => write!(f, "!{k}"),
+        }
+    }
+}
+
+/// A parsed Kubernetes label selector, e.g. `app=web,tier!=cache` or
+/// `env in (prod,stage)`. Every requirement must match for a row to pass.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LabelSelector {
+    requirements: Vec<Requirement>,
+}
+
+impl LabelSelector {
+    pub fn parse(input: &str) -> Result<Self, SelectorError> {
+        let requirements = split_terms(input)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, term)| parse_requirement(i, term))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { requirements })
+    }
+
+    pub fn matches(&self, labels: &Labels) -> bool {
+        self.requirements.iter().all(|r| r.matches(labels))
+    }
+}
+
+impl fmt::Display for LabelSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.requirements.iter().join(","))
+    }
+}
+
+/// Split on commas that are not inside a `( ... )` value set.
+fn split_terms(input: &str) -> Result<Vec<&str>, SelectorError> {
+    let mut terms = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or(SelectorError::UnbalancedParens)?
+            }
+            ',' if depth == 0 => {
+                terms.push(&input[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(SelectorError::UnbalancedParens);
+    }
+    terms.push(&input[start..]);
+    Ok(terms)
+}
+
+fn parse_requirement(position: usize, term: &str) -> Result<Requirement, SelectorError> {
+    let term = term.trim();
+    if term.is_empty() {
+        return Err(SelectorError::EmptyRequirement(position));
+    }
+    if let Some(key) = term.strip_prefix('!') {
+        return Ok(Requirement::DoesNotExist(key_of(key)?));
+    }
+    if let Some((key, value)) = term.split_once("!=") {
+        return Ok(Requirement::NotEquals(key_of(key)?, value_of(value)?));
+    }
+    if let Some((key, value)) = term.split_once("==").or_else(|| term.split_once('=')) {
+        return Ok(Requirement::Equals(key_of(key)?, value_of(value)?));
+    }
+    let Some((key, rest)) = term.split_once(char::is_whitespace) else {
+        return Ok(Requirement::Exists(key_of(term)?));
+    };
+    let rest = rest.trim_start();
+    let (op, set) = match rest.find('(') {
+        Some(i) => rest.split_at(i),
+        None => rest.split_once(char::is_whitespace).unwrap_or((rest, "")),
+    };
+    let values = set_of(op.trim(), set.trim())?;
+    match op.trim() {
+        "in" => Ok(Requirement::In(key_of(key)?, values)),
+        "notin" => Ok(Requirement::NotIn(key_of(key)?, values)),
+        other => Err(SelectorError::UnknownOperator(other.to_string())),
+    }
+}
+
+fn set_of(op: &str, set: &str) -> Result<Vec<String>, SelectorError> {
+    set.strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .ok_or_else(|| SelectorError::ExpectedSet(op.to_string()))?
+        .split(',')
+        .map(value_of)
+        .collect()
+}
+
+fn key_of(key: &str) -> Result<String, SelectorError> {
+    let key = key.trim();
+    let valid = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'));
+    if valid {
+        Ok(key.to_string())
+    } else {
+        Err(SelectorError::InvalidKey(key.to_string()))
+    }
+}
+
+fn value_of(value: &str) -> Result<String, SelectorError> {
+    let value = value.trim();
+    if value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        Ok(value.to_string())
+    } else {
+        Err(SelectorError::InvalidValue(value.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::selector::{LabelSelector, Labels, Requirement, SelectorError};
+
+    fn labels(pairs: &[(&str, &str)]) -> Labels {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_equality_requirements() {
+        let selector = LabelSelector::parse("app=web,tier!=cache").unwrap();
+
+        assert!(selector.matches(&labels(&[("app", "web"), ("tier", "frontend")])));
+        assert!(selector.matches(&labels(&[("app", "web")])));
+        assert!(!selector.matches(&labels(&[("app", "web"), ("tier", "cache")])));
+        assert!(!selector.matches(&labels(&[("app", "api")])));
+        assert_eq!("app=web,tier!=cache", selector.to_string());
+    }
+
+    #[test]
+    fn test_parse_set_requirements() {
+        let selector = LabelSelector::parse("env in (prod, stage), tier notin (cache)").unwrap();
+
+        assert!(selector.matches(&labels(&[("env", "prod"), ("tier", "backend")])));
+        assert!(selector.matches(&labels(&[("env", "stage")])));
+        assert!(!selector.matches(&labels(&[("env", "dev")])));
+        assert!(!selector.matches(&labels(&[("env", "prod"), ("tier", "cache")])));
+    }
+
+    #[test]
+    fn test_parse_existence_requirements() {
+        let selector = LabelSelector::parse("app,!canary").unwrap();
+
+        assert_eq!(
+            vec![
+                Requirement::Exists("app".to_string()),
+                Requirement::DoesNotExist("canary".to_string())
+            ],
+            selector.requirements
+        );
+        assert!(selector.matches(&labels(&[("app", "web")])));
+        assert!(!selector.matches(&labels(&[("app", "web"), ("canary", "true")])));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(SelectorError::EmptyRequirement(1)),
+            LabelSelector::parse("app=web,")
+        );
+        assert_eq!(
+            Err(SelectorError::UnbalancedParens),
+            LabelSelector::parse("env in (prod")
+        );
+        assert_eq!(
+            Err(SelectorError::ExpectedSet("in".to_string())),
+            LabelSelector::parse("env in prod")
+        );
+        assert_eq!(
+            Err(SelectorError::UnknownOperator("within".to_string())),
+            LabelSelector::parse("env within (prod)")
+        );
+        assert_eq!(
+            Err(SelectorError::InvalidKey("a b".to_string())),
+            LabelSelector::parse("a b=c")
+        );
+    }
+}
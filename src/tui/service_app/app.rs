@@ -0,0 +1,21 @@
+use crate::tui::data::{generate_service_recs, Service};
+use crate::tui::table_app::TableApp;
+
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) table: TableApp<Service>,
+    pub(crate) scope: Option<String>,
+}
+
+impl App {
+    pub fn new() -> Self {
+        Self::with_services(generate_service_recs(), None)
+    }
+
+    pub fn with_services(data_vec: Vec<Service>, scope: Option<String>) -> Self {
+        Self {
+            table: TableApp::new(data_vec, 2),
+            scope,
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use std::cmp::Ordering;
+
+use itertools::{EitherOrBoth, Itertools};
+
+use crate::tui::columns::ColumnLayout;
+use crate::tui::data::parse_age;
+use crate::tui::style::ITEM_HEIGHT;
+use crate::tui::table_ui::TuiTableState;
+use crate::tui::table_view::{column_at, Column, TableRow};
+
+/// Most columns sorted by at once from header clicks.
+const MAX_SORT_KEYS: usize = 3;
+
+/// How the cells of a column compare.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortBy {
+    /// Text with embedded numbers in value order, so `pod-9` comes before
+    /// `pod-10`.
+    Natural,
+    /// The leading number, e.g. 250 for "250m ▃▄" or 2 for "2/3".
+    Number,
+    /// An age such as "3h" or "200d".
+    Age,
+}
+
+/// One column to sort by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SortKey {
+    pub(crate) column: usize,
+    pub(crate) descending: bool,
+}
+
+impl SortKey {
+    pub const fn ascending(column: usize) -> Self {
+        Self {
+            column,
+            descending: false,
+        }
+    }
+}
+
+/// `a` against `b` comparing digit runs by value and the rest ignoring case.
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let chunks = |text: &str| {
+        text.chars()
+            .group_by(char::is_ascii_digit)
+            .into_iter()
+            .map(|(digits, chunk)| (digits, chunk.collect::<String>()))
+            .collect_vec()
+    };
+    for pair in chunks(a).iter().zip_longest(chunks(b).iter()) {
+        let ordering = match pair {
+            EitherOrBoth::Left(_) => return Ordering::Greater,
+            EitherOrBoth::Right(_) => return Ordering::Less,
+            EitherOrBoth::Both((true, a), (true, b)) => {
+                let (a, b) = (a.trim_start_matches('0'), b.trim_start_matches('0'));
+                a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+            }
+            EitherOrBoth::Both((_, a), (_, b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+/// The leading number of `text`, e.g. 3 for "3/4".
+pub fn leading_number(text: &str) -> Option<f64> {
+    let text = text.trim_start();
+    let end = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+        .unwrap_or(text.len());
+    text[..end].parse().ok()
+}
+
+/// The age at the start of `text` in seconds, e.g. 7200 for "2h (x3)".
+pub fn leading_age(text: &str) -> Option<f64> {
+    let text = text.split_whitespace().next().unwrap_or_default();
+    #[allow(clippy::cast_precision_loss)]
+    parse_age(text).map(|secs| secs as f64)
+}
+
+impl SortBy {
+    /// Cells without a value, e.g. "<none>", sort after every value.
+    pub fn compare(self, a: &str, b: &str) -> Ordering {
+        let by_value = |a: Option<f64>, b: Option<f64>| match (a, b) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        };
+        match self {
+            Self::Natural => natural_cmp(a, b),
+            Self::Number => by_value(leading_number(a), leading_number(b)),
+            Self::Age => by_value(leading_age(a), leading_age(b)),
+        }
+    }
+}
+
+/// Stable sort of `items` by `order`, the first key deciding first.
+pub fn sort_rows<T: TableRow>(items: &mut Vec<T>, order: &[SortKey]) {
+    if order.is_empty() {
+        return;
+    }
+    let cells = items.iter().map(TableRow::cells).collect_vec();
+    let compare = |a: usize, b: usize| {
+        order
+            .iter()
+            .map(|key| {
+                let ordering = T::COLUMNS[key.column]
+                    .sort
+                    .compare(&cells[a][key.column], &cells[b][key.column]);
+                if key.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    };
+    let indexes = (0..items.len())
+        .sorted_by(|&a, &b| compare(a, b))
+        .collect_vec();
+    let mut slots = std::mem::take(items).into_iter().map(Some).collect_vec();
+    *items = indexes
+        .into_iter()
+        .filter_map(|i| slots[i].take())
+        .collect();
+}
+
+/// `order` as typed in the sort prompt: titles separated by commas, each
+/// prefixed with `-` when descending, e.g. "Status,-Age".
+pub fn format_order(columns: &[Column], order: &[SortKey]) -> String {
+    order
+        .iter()
+        .map(|key| {
+            let sign = if key.descending { "-" } else { "" };
+            format!("{sign}{}", columns[key.column].title)
+        })
+        .join(",")
+}
+
+/// Inverse of [`format_order`]; titles match ignoring case.
+pub fn parse_order(columns: &[Column], input: &str) -> Result<Vec<SortKey>, String> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+        .map(|title| {
+            let (descending, title) = match title.strip_prefix('-') {
+                Some(title) => (true, title.trim()),
+                None => (false, title.trim_start_matches('+').trim()),
+            };
+            columns
+                .iter()
+                .position(|column| column.title.eq_ignore_ascii_case(title))
+                .map(|column| SortKey { column, descending })
+                .ok_or_else(|| {
+                    format!(
+                        "unknown column '{title}', expected one of: {}",
+                        columns.iter().map(|column| column.title).join(", ")
+                    )
+                })
+        })
+        .collect()
+}
+
+/// A change of sort order asked for by a key, a header click or the prompt.
+pub enum SortAction {
+    /// Sort by the shown column right (`true`) or left of the current one.
+    Step(bool),
+    Reverse,
+    /// A click at `x` on the header of a table `width` cells wide. The
+    /// clicked column becomes the only key, or flips if it already is; with
+    /// `add` it is added as the next key instead.
+    Click {
+        x: u16,
+        width: u16,
+        add: bool,
+    },
+    Prompt(String),
+}
+
+/// A [`TuiTableState`] whose rows can be sorted by any of their columns.
+pub trait SortedTable: TuiTableState<Item: TableRow> {
+    fn get_items_mut(&mut self) -> &mut Vec<Self::Item>;
+    fn get_sort(&self) -> &[SortKey];
+    fn set_sort(&mut self, order: Vec<SortKey>);
+    /// Widest cell of each column, as the table is drawn with.
+    fn get_widths(&self) -> &[u16];
+    /// Order and visibility of the columns, as the table is drawn with.
+    fn get_layout(&self) -> &ColumnLayout;
+
+    /// Select the row with `key` again after the rows moved.
+    fn reselect(&mut self, key: Option<String>) {
+        let Some(i) =
+            key.and_then(|key| self.get_items().iter().position(|item| item.key() == key))
+        else {
+            return;
+        };
+        self.get_state().select(Some(i));
+        let scroll_state = self.get_scroll_state().position(i * ITEM_HEIGHT);
+        self.set_scroll_state(scroll_state);
+    }
+
+    /// Sort by `order`, keeping the selected row selected.
+    fn sort(&mut self, order: Vec<SortKey>) {
+        let selected = self.selected_item().map(TableRow::key);
+        sort_rows(self.get_items_mut(), &order);
+        self.set_sort(order);
+        self.reselect(selected);
+    }
+
+    /// Apply `action`; an `Err` explains input the sort prompt could not use.
+    fn apply_sort(&mut self, action: SortAction) -> Result<(), String> {
+        let columns = Self::Item::COLUMNS;
+        let current = self.get_sort().to_vec();
+        let order = match action {
+            SortAction::Step(right) => {
+                let shown = self.get_layout().shown(columns);
+                let position = current
+                    .first()
+                    .and_then(|key| shown.iter().position(|&i| i == key.column));
+                let next = match (position, right) {
+                    (None, true) => 0,
+                    (None, false) => shown.len() - 1,
+                    (Some(p), true) => (p + 1) % shown.len(),
+                    (Some(p), false) => (p + shown.len() - 1) % shown.len(),
+                };
+                let descending = current.first().is_some_and(|key| key.descending);
+                vec![SortKey {
+                    column: shown[next],
+                    descending,
+                }]
+            }
+            SortAction::Reverse => {
+                let mut order = current;
+                if let Some(key) = order.first_mut() {
+                    key.descending = !key.descending;
+                }
+                order
+            }
+            SortAction::Click { x, width, add } => {
+                let Some(column) = column_at::<Self::Item>(
+                    self.get_widths(),
+                    &current,
+                    self.get_layout(),
+                    width,
+                    x,
+                ) else {
+                    return Ok(());
+                };
+                let mut order = if add {
+                    current
+                } else {
+                    current.into_iter().take(1).collect()
+                };
+                match order.iter().position(|key| key.column == column) {
+                    Some(i) => order[i].descending = !order[i].descending,
+                    None if !add => order = vec![SortKey::ascending(column)],
+                    None if order.len() < MAX_SORT_KEYS => order.push(SortKey::ascending(column)),
+                    None => {}
+                }
+                order
+            }
+            SortAction::Prompt(input) => parse_order(columns, &input)?,
+        };
+        self.sort(order);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::sort::{format_order, natural_cmp, parse_order, sort_rows, SortBy, SortKey};
+    use crate::tui::table_view::{TableRow, TestRow};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_natural_cmp() {
+        assert_eq!(Ordering::Less, natural_cmp("pod-9", "pod-10"));
+        assert_eq!(Ordering::Less, natural_cmp("Api", "web"));
+        assert_eq!(Ordering::Less, natural_cmp("node", "node-1"));
+        assert_eq!(Ordering::Equal, natural_cmp("pod-007", "pod-7"));
+        assert_eq!(Ordering::Greater, natural_cmp("replica10-a", "replica9-z"));
+    }
+
+    #[test]
+    fn test_value_ordering() {
+        assert_eq!(
+            Ordering::Less,
+            SortBy::Number.compare("250m ▃▄", "1250m ▄▅")
+        );
+        assert_eq!(Ordering::Less, SortBy::Number.compare("2/3", "10/10"));
+        assert_eq!(Ordering::Less, SortBy::Number.compare("3", "<none>"));
+        assert_eq!(Ordering::Less, SortBy::Age.compare("59m", "2h"));
+        assert_eq!(Ordering::Greater, SortBy::Age.compare("200d", "3h"));
+    }
+
+    #[test]
+    fn test_multi_column_sort() {
+        let mut rows = vec![
+            TestRow::new("web-10", "Running").seen("2h"),
+            TestRow::new("web-9", "Pending").seen("5m"),
+            TestRow::new("api", "Running").seen("30d"),
+            TestRow::new("db", "Pending").seen("1d"),
+        ];
+        let order = parse_order(TestRow::COLUMNS, "status, -last seen").unwrap();
+        assert_eq!(
+            vec![
+                SortKey::ascending(1),
+                SortKey {
+                    column: 2,
+                    descending: true
+                }
+            ],
+            order
+        );
+        assert_eq!("Status,-Last Seen", format_order(TestRow::COLUMNS, &order));
+
+        sort_rows(&mut rows, &order);
+        let names = rows.iter().map(|row| row.name).collect::<Vec<_>>();
+        assert_eq!(vec!["db", "web-9", "api", "web-10"], names);
+
+        sort_rows(&mut rows, &[SortKey::ascending(0)]);
+        let names = rows.iter().map(|row| row.name).collect::<Vec<_>>();
+        assert_eq!(vec!["api", "db", "web-9", "web-10"], names);
+
+        assert!(parse_order(TestRow::COLUMNS, "Restarts").is_err());
+    }
+}
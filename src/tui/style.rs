@@ -1,3 +1,6 @@
+use crate::tui::certs::Urgency;
+use crate::tui::data::Health;
+use crate::tui::metrics::UsageLevel;
 use ratatui::prelude::*;
 use style::palette::tailwind;
 
@@ -8,7 +11,7 @@ pub const PALETTES: [tailwind::Palette; 4] = [
     tailwind::INDIGO,
 ];
 pub const INFO_TEXT: &str =
-    "(q) quit | (Esc) previous | (↑) move up | (↓) move down | (c) next color";
+    "(q) quit | (Esc) previous | (↑↓ PgUp/PgDn g/G) move | (c) next color | (l) labels | (e) events | (/) search | (f) filter | (w) where | (space/V) mark | (o) columns | (</>) sort | (:) resource";
 
 pub const ITEM_HEIGHT: usize = 4;
 
@@ -22,6 +25,22 @@ pub struct TableColors {
     pub(crate) normal_row_color: Color,
     pub(crate) alt_row_color: Color,
     pub(crate) footer_border_color: Color,
+    pub(crate) degraded_row_fg: Color,
+    pub(crate) degraded_row_bg: Color,
+    pub(crate) crash_loop_row_fg: Color,
+    pub(crate) crash_loop_row_bg: Color,
+    pub(crate) pending_row_fg: Color,
+    pub(crate) pending_row_bg: Color,
+    pub(crate) terminating_row_fg: Color,
+    pub(crate) terminating_row_bg: Color,
+    pub(crate) warning_row_fg: Color,
+    pub(crate) warning_row_bg: Color,
+    pub(crate) over_request_fg: Color,
+    pub(crate) near_limit_fg: Color,
+    pub(crate) search_match_fg: Color,
+    pub(crate) search_match_bg: Color,
+    pub(crate) filter_match_fg: Color,
+    pub(crate) mark_fg: Color,
 }
 
 impl TableColors {
@@ -35,6 +54,96 @@ impl TableColors {
             normal_row_color: tailwind::SLATE.c950,
             alt_row_color: tailwind::SLATE.c900,
             footer_border_color: color.c400,
+            degraded_row_fg: tailwind::AMBER.c300,
+            degraded_row_bg: tailwind::AMBER.c950,
+            crash_loop_row_fg: tailwind::ROSE.c200,
+            crash_loop_row_bg: tailwind::ROSE.c900,
+            pending_row_fg: tailwind::SKY.c300,
+            pending_row_bg: tailwind::SKY.c950,
+            terminating_row_fg: tailwind::SLATE.c500,
+            terminating_row_bg: tailwind::SLATE.c800,
+            warning_row_fg: tailwind::ORANGE.c300,
+            warning_row_bg: tailwind::ORANGE.c950,
+            over_request_fg: tailwind::AMBER.c400,
+            near_limit_fg: tailwind::RED.c500,
+            search_match_fg: tailwind::SLATE.c950,
+            search_match_bg: tailwind::YELLOW.c300,
+            filter_match_fg: tailwind::YELLOW.c300,
+            mark_fg: color.c300,
+        }
+    }
+
+    /// Healthy rows keep the even/odd striping; problem rows get the colors of
+    /// their state so they stand out in a long list.
+    pub const fn row_style(&self, health: Health, index: usize) -> Style {
+        let (fg, bg) = match health {
+            Health::Healthy => match index % 2 {
+                0 => (self.row_fg, self.normal_row_color),
+                _ => (self.row_fg, self.alt_row_color),
+            },
+            Health::Degraded => (self.degraded_row_fg, self.degraded_row_bg),
+            Health::CrashLoop => (self.crash_loop_row_fg, self.crash_loop_row_bg),
+            Health::Pending => (self.pending_row_fg, self.pending_row_bg),
+            Health::Terminating => (self.terminating_row_fg, self.terminating_row_bg),
+        };
+        Style::new().fg(fg).bg(bg)
+    }
+
+    pub const fn event_row_style(&self, warning: bool, index: usize) -> Style {
+        if warning {
+            Style::new().fg(self.warning_row_fg).bg(self.warning_row_bg)
+        } else {
+            self.row_style(Health::Healthy, index)
+        }
+    }
+
+    /// Expired certificates look like crash looping pods, those due within a
+    /// week like warnings and within a month like degraded rows.
+    pub const fn urgency_style(&self, urgency: Urgency, index: usize) -> Style {
+        match urgency {
+            Urgency::Expired => self.row_style(Health::CrashLoop, index),
+            Urgency::Critical => Style::new().fg(self.warning_row_fg).bg(self.warning_row_bg),
+            Urgency::Soon => self.row_style(Health::Degraded, index),
+            Urgency::Fine => self.row_style(Health::Healthy, index),
+        }
+    }
+
+    /// Lint counts stand out once there are any findings.
+    pub const fn lint_style(&self, findings: usize) -> Style {
+        match findings {
+            0 => Style::new(),
+            _ => Style::new().fg(self.warning_row_fg),
+        }
+    }
+
+    /// Text matching the `/` search.
+    pub const fn search_match_style(&self) -> Style {
+        Style::new()
+            .fg(self.search_match_fg)
+            .bg(self.search_match_bg)
+    }
+
+    /// Characters matched by the fuzzy filter, over the row colors.
+    pub const fn filter_match_style(&self) -> Style {
+        Style::new()
+            .fg(self.filter_match_fg)
+            .add_modifier(Modifier::BOLD.union(Modifier::UNDERLINED))
+    }
+
+    /// Gutter symbol of marked rows.
+    pub const fn mark_style(&self) -> Style {
+        Style::new().fg(self.mark_fg).add_modifier(Modifier::BOLD)
+    }
+
+    /// Usage above the request is amber, close to the limit red, anything
+    /// else keeps the row style.
+    pub fn usage_style(&self, level: UsageLevel) -> Style {
+        match level {
+            UsageLevel::Normal => Style::new(),
+            UsageLevel::OverRequest => Style::new().fg(self.over_request_fg),
+            UsageLevel::NearLimit => Style::new()
+                .fg(self.near_limit_fg)
+                .add_modifier(Modifier::BOLD),
         }
     }
 }
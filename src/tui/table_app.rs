@@ -0,0 +1,244 @@
+use std::collections::{BTreeSet, HashMap};
+
+use ratatui::prelude::*;
+use ratatui::widgets::{ScrollbarState, TableState};
+
+use crate::tui::columns::{ColumnChooser, ColumnLayout, ColumnStore};
+use crate::tui::data::constraint_len_calculator;
+use crate::tui::filter::{filter_status, Filter, FilteredTable};
+use crate::tui::marks::{marks_status, MarkedTable, Marks};
+use crate::tui::query::Query;
+use crate::tui::search::{Search, SearchTable};
+use crate::tui::selector::LabelSelector;
+use crate::tui::sort::{format_order, SortKey, SortedTable};
+use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
+use crate::tui::table_ui::{render_scrollbar, TuiTableState};
+use crate::tui::table_view::{TableRow, TableView};
+
+/// The rows of a table view and what the user did to them: the selection,
+/// sort, search, filters and marks. Views keep one next to their own state.
+#[derive(Clone, Debug)]
+pub struct TableApp<T> {
+    pub(crate) state: TableState,
+    /// The rows shown, in the order shown.
+    pub(crate) items: Vec<T>,
+    /// Every row of the view, whatever the label selector and filters hide.
+    pub(crate) all_items: Vec<T>,
+    pub(crate) selector: Option<LabelSelector>,
+    pub(crate) longest_item_lens: Vec<u16>,
+    pub(crate) sort: Vec<SortKey>,
+    pub(crate) search: Option<Search>,
+    pub(crate) filter: Option<Filter>,
+    pub(crate) query: Option<Query>,
+    pub(crate) marks: Marks,
+    /// Order and visibility of the columns, from the column store.
+    pub(crate) layout: ColumnLayout,
+    pub(crate) scroll_state: ScrollbarState,
+    pub(crate) colors: TableColors,
+    /// Lines the rows had below the header when last drawn.
+    pub(crate) viewport: u16,
+    color_index: usize,
+}
+
+impl<T: TableRow + Clone> TableApp<T> {
+    /// `rows` as given with the first one selected, in the palette at
+    /// `color_index`.
+    pub fn new(rows: Vec<T>, color_index: usize) -> Self {
+        Self {
+            state: TableState::default().with_selected(0),
+            longest_item_lens: constraint_len_calculator(&rows),
+            selector: None,
+            sort: Vec::new(),
+            search: None,
+            filter: None,
+            query: None,
+            marks: Marks::default(),
+            layout: ColumnLayout::default(),
+            scroll_state: ScrollbarState::new(rows.len().saturating_sub(1) * ITEM_HEIGHT),
+            colors: TableColors::new(&PALETTES[0]),
+            viewport: 0,
+            color_index,
+            all_items: rows.clone(),
+            items: rows,
+        }
+    }
+
+    /// Show only the rows whose labels match `selector`, or every row when
+    /// it is `None`; rows without labels never match.
+    pub fn set_selector(&mut self, selector: Option<LabelSelector>) {
+        self.selector = selector;
+        self.refilter();
+        self.reset_selection();
+    }
+
+    /// Change every row in place with `change`, keeping the order, the
+    /// filters and the selection.
+    pub fn update_rows(&mut self, mut change: impl FnMut(&mut T)) {
+        self.all_items.iter_mut().for_each(&mut change);
+        let rows = self
+            .all_items
+            .iter()
+            .map(|row| (row.key(), row))
+            .collect::<HashMap<_, _>>();
+        for item in &mut self.items {
+            if let Some(row) = rows.get(&item.key()) {
+                item.clone_from(row);
+            }
+        }
+        self.longest_item_lens = constraint_len_calculator(&self.items);
+    }
+
+    /// Sort again after the cells of `columns` changed in place, when the
+    /// order depends on them.
+    pub fn resort_after(&mut self, columns: &[usize]) {
+        if self.sort.iter().any(|key| columns.contains(&key.column)) {
+            self.sort(self.sort.clone());
+        }
+    }
+
+    /// Take the column layout saved for `T` in `store`.
+    pub fn load_layout(&mut self, store: &ColumnStore) {
+        self.layout = store.layout(T::KIND);
+    }
+
+    /// The column chooser for the columns of `T`.
+    pub fn column_chooser(&self) -> ColumnChooser {
+        ColumnChooser::open::<T>(&self.layout, self.color_index)
+    }
+
+    /// The sort order as the sort prompt takes it.
+    pub fn sort_order(&self) -> String {
+        format_order(T::COLUMNS, &self.sort)
+    }
+
+    /// Draw the rows and their scrollbar in `area`.
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let table = TableView::new(&self.items, &self.longest_item_lens, &self.colors)
+            .sorted_by(&self.sort)
+            .searched(self.search.as_ref())
+            .filtered(self.filter.as_ref())
+            .marked(&self.marks)
+            .laid_out(&self.layout);
+        f.render_stateful_widget(table, area, &mut self.state);
+        self.viewport = area.height.saturating_sub(1);
+        render_scrollbar(f, area, &mut self.scroll_state);
+    }
+
+    /// `status` with the filters and marks appended, for the footer.
+    pub fn status(&mut self, status: Option<String>) -> Option<String> {
+        marks_status(filter_status(status, self), self)
+    }
+}
+
+impl<T> TuiTableState for TableApp<T> {
+    type Item = T;
+
+    fn get_items(&self) -> &[Self::Item] {
+        &self.items
+    }
+
+    fn get_state(&mut self) -> &mut TableState {
+        &mut self.state
+    }
+
+    fn get_scroll_state(&self) -> &ScrollbarState {
+        &self.scroll_state
+    }
+
+    fn set_scroll_state(&mut self, scroll_state: ScrollbarState) {
+        self.scroll_state = scroll_state;
+    }
+
+    fn get_table_colors(&self) -> &TableColors {
+        &self.colors
+    }
+
+    fn set_table_colors(&mut self, colors: TableColors) {
+        self.colors = colors;
+    }
+
+    fn get_color_index(&self) -> usize {
+        self.color_index
+    }
+
+    fn set_color_index(&mut self, color_index: usize) {
+        self.color_index = color_index;
+    }
+}
+
+impl<T: TableRow> SortedTable for TableApp<T> {
+    fn get_items_mut(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.items
+    }
+
+    fn get_sort(&self) -> &[SortKey] {
+        &self.sort
+    }
+
+    fn set_sort(&mut self, order: Vec<SortKey>) {
+        self.sort = order;
+    }
+
+    fn get_widths(&self) -> &[u16] {
+        &self.longest_item_lens
+    }
+
+    fn get_layout(&self) -> &ColumnLayout {
+        &self.layout
+    }
+}
+
+impl<T: TableRow> SearchTable for TableApp<T> {
+    fn get_search(&self) -> Option<&Search> {
+        self.search.as_ref()
+    }
+
+    fn set_search(&mut self, search: Option<Search>) {
+        self.search = search;
+    }
+}
+
+impl<T: TableRow + Clone> FilteredTable for TableApp<T> {
+    fn get_filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn set_filter(&mut self, filter: Option<Filter>) {
+        self.filter = filter;
+    }
+
+    fn get_query(&self) -> Option<&Query> {
+        self.query.as_ref()
+    }
+
+    fn set_query(&mut self, query: Option<Query>) {
+        self.query = query;
+    }
+
+    fn get_unfiltered(&self) -> Vec<Self::Item> {
+        match &self.selector {
+            Some(selector) => self
+                .all_items
+                .iter()
+                .filter(|item| item.labels().is_some_and(|labels| selector.matches(labels)))
+                .cloned()
+                .collect(),
+            None => self.all_items.clone(),
+        }
+    }
+}
+
+impl<T: TableRow + Clone> MarkedTable for TableApp<T> {
+    fn get_marks(&self) -> &Marks {
+        &self.marks
+    }
+
+    fn get_marks_mut(&mut self) -> &mut Marks {
+        &mut self.marks
+    }
+
+    fn delete_rows(&mut self, keys: &BTreeSet<String>) {
+        self.all_items.retain(|item| !keys.contains(&item.key()));
+        self.items.retain(|item| !keys.contains(&item.key()));
+    }
+}
@@ -1,15 +1,49 @@
-use crate::tui::style::{ITEM_HEIGHT, PALETTES, TableColors};
-use ratatui::widgets::{ScrollbarState, TableState};
+use crate::tui::columns::ColumnChooser;
+use crate::tui::lint::{Finding, LintConfig};
+use crate::tui::prompt::Prompt;
+use crate::tui::style::{TableColors, INFO_TEXT, ITEM_HEIGHT, PALETTES};
+use ratatui::prelude::*;
+use ratatui::widgets::{
+    Block, BorderType, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+    TableState, Wrap,
+};
+
+/// A move of the selection from the navigation keys, repeated or aimed by a
+/// count typed before it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Motion {
+    /// `count` rows down, stopping at the last row.
+    Down,
+    /// `count` rows up, stopping at the first row.
+    Up,
+    PageDown,
+    PageUp,
+    HalfPageDown,
+    HalfPageUp,
+    /// The first row, or row `count`.
+    First,
+    /// The last row, or row `count`.
+    Last,
+}
 
 pub trait TuiTableState {
     type Item; // if items are of a specific type
 
-    fn next(&mut self) {
+    /// Lines each row takes up on screen.
+    const ROW_HEIGHT: usize = ITEM_HEIGHT;
+
+    /// Select the next row; past the last one, the first when `wraparound`.
+    fn next(&mut self, wraparound: bool) {
+        if self.get_items().is_empty() {
+            return;
+        }
         let i = self.get_state().selected().map_or(0, |i| {
-            if i >= self.get_items().len() - 1 {
+            if i < self.get_items().len() - 1 {
+                i + 1
+            } else if wraparound {
                 0
             } else {
-                i + 1
+                i
             }
         });
 
@@ -18,12 +52,19 @@ pub trait TuiTableState {
         self.set_scroll_state(new_scroll_state);
     }
 
-    fn previous(&mut self) {
+    /// Select the previous row; before the first one, the last when
+    /// `wraparound`.
+    fn previous(&mut self, wraparound: bool) {
+        if self.get_items().is_empty() {
+            return;
+        }
         let i = self.get_state().selected().map_or(0, |i| {
-            if i == 0 {
+            if i > 0 {
+                i - 1
+            } else if wraparound {
                 self.get_items().len() - 1
             } else {
-                i - 1
+                0
             }
         });
 
@@ -32,6 +73,77 @@ pub trait TuiTableState {
 
         self.set_scroll_state(new_scroll_state);
     }
+    fn reset_selection(&mut self) {
+        let len = self.get_items().len();
+        self.get_state().select((len > 0).then_some(0));
+        self.set_scroll_state(ScrollbarState::new(len.saturating_sub(1) * ITEM_HEIGHT));
+    }
+
+    /// Select the first row after the selection, or before it when `forward`
+    /// is false, for which `matches` holds, wrapping around the ends; `false`
+    /// when no row does.
+    fn select_match(&mut self, forward: bool, matches: impl Fn(&Self::Item) -> bool) -> bool {
+        let len = self.get_items().len();
+        let selected = self.get_state().selected().unwrap_or(len.saturating_sub(1));
+        let found = (1..=len)
+            .map(|step| {
+                if forward {
+                    (selected + step) % len
+                } else {
+                    (selected + len - step % len) % len
+                }
+            })
+            .find(|&i| matches(&self.get_items()[i]));
+        let Some(i) = found else {
+            return false;
+        };
+        self.get_state().select(Some(i));
+        let new_scroll_state = self.get_scroll_state().position(i * ITEM_HEIGHT);
+        self.set_scroll_state(new_scroll_state);
+        true
+    }
+
+    /// Select row `i`, or the last row when there are fewer.
+    fn select_row(&mut self, i: usize) {
+        let Some(last) = self.get_items().len().checked_sub(1) else {
+            return;
+        };
+        let i = i.min(last);
+        self.get_state().select(Some(i));
+        let new_scroll_state = self.get_scroll_state().position(i * ITEM_HEIGHT);
+        self.set_scroll_state(new_scroll_state);
+    }
+
+    /// Rows shown at once in `height` lines below the header.
+    fn page_rows(&self, height: u16) -> usize {
+        (usize::from(height) / Self::ROW_HEIGHT).max(1)
+    }
+
+    /// Move the selection by `motion`, `count` times or to row `count` when
+    /// one was typed; pages are as many rows as fit in `height` lines below
+    /// the header. These moves stop at the ends rather than wrap around.
+    fn apply_motion(&mut self, motion: Motion, count: Option<usize>, height: u16) {
+        let selected = self.get_state().selected().unwrap_or(0);
+        let times = count.unwrap_or(1);
+        let page = self.page_rows(height);
+        let i = match motion {
+            Motion::Down => selected.saturating_add(times),
+            Motion::Up => selected.saturating_sub(times),
+            Motion::PageDown => selected.saturating_add(page * times),
+            Motion::PageUp => selected.saturating_sub(page * times),
+            Motion::HalfPageDown => selected.saturating_add((page / 2).max(1) * times),
+            Motion::HalfPageUp => selected.saturating_sub((page / 2).max(1) * times),
+            Motion::First => count.map_or(0, |row| row.saturating_sub(1)),
+            Motion::Last => count.map_or(usize::MAX, |row| row.saturating_sub(1)),
+        };
+        self.select_row(i);
+    }
+
+    fn selected_item(&mut self) -> Option<&Self::Item> {
+        let i = self.get_state().selected()?;
+        self.get_items().get(i)
+    }
+
     fn next_color(&mut self) {
         //self.color_index = (self.color_index + 1) % PALETTES.len();
         let new_color_index = (self.get_color_index() + 1) % PALETTES.len();
@@ -53,3 +165,239 @@ pub trait TuiTableState {
     fn get_color_index(&self) -> usize;
     fn set_color_index(&mut self, color_index: usize);
 }
+
+/// Footer shared by every table view: the key help, or the active prompt while
+/// the user is typing. `status` is shown in the border, e.g. the active selector.
+pub fn render_footer(
+    f: &mut Frame,
+    colors: &TableColors,
+    area: Rect,
+    prompt: Option<&Prompt>,
+    status: Option<String>,
+) {
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::new().fg(colors.footer_border_color))
+        .border_type(BorderType::Double);
+    let (text, alignment) = match prompt {
+        Some(prompt) => {
+            if let Some(error) = &prompt.error {
+                block = block.title(Span::styled(
+                    format!(" {error} "),
+                    Style::new().fg(Color::Red),
+                ));
+            }
+            (prompt.text(), Alignment::Left)
+        }
+        None => {
+            if let Some(status) = status {
+                block = block.title(format!(" {status} "));
+            }
+            (INFO_TEXT.to_string(), Alignment::Center)
+        }
+    };
+    let info_footer = Paragraph::new(Line::from(text))
+        .style(Style::new().fg(colors.row_fg).bg(colors.buffer_bg))
+        .alignment(alignment)
+        .block(block);
+    f.render_widget(info_footer, area);
+}
+
+/// `notice` in red at the right of the footer's top border, drawn over
+/// whichever view rendered the footer.
+pub fn render_notice(f: &mut Frame, notice: &str) {
+    let area = f.size();
+    let footer = Layout::vertical([Constraint::Min(5), Constraint::Length(3)]).split(area)[1];
+    let border = Rect {
+        height: 1,
+        ..footer.inner(&Margin {
+            vertical: 0,
+            horizontal: 1,
+        })
+    };
+    let text = Span::styled(format!(" {notice} "), Style::new().fg(Color::Red));
+    f.render_widget(Paragraph::new(text).alignment(Alignment::Right), border);
+}
+
+/// Vertical scrollbar along the right edge of a table.
+pub fn render_scrollbar(f: &mut Frame, area: Rect, scroll_state: &mut ScrollbarState) {
+    f.render_stateful_widget(
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None),
+        area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        }),
+        scroll_state,
+    );
+}
+
+/// `percent_x` by `percent_y` of `area`, centered.
+pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::vertical([
+        Constraint::Percentage((100 - percent_y) / 2),
+        Constraint::Percentage(percent_y),
+        Constraint::Percentage((100 - percent_y) / 2),
+    ])
+    .split(area);
+    Layout::horizontal([
+        Constraint::Percentage((100 - percent_x) / 2),
+        Constraint::Percentage(percent_x),
+        Constraint::Percentage((100 - percent_x) / 2),
+    ])
+    .split(vertical[1])[1]
+}
+
+/// Popup listing the lint findings of the selected row, and which rules ran.
+pub fn render_lint_popup(
+    f: &mut Frame,
+    colors: &TableColors,
+    name: &str,
+    findings: &[Finding],
+    config: &LintConfig,
+) {
+    let area = centered_rect(70, 50, f.size());
+    let mut lines = if findings.is_empty() {
+        vec![Line::from("no findings")]
+    } else {
+        findings
+            .iter()
+            .map(|finding| Line::from(finding.to_string()))
+            .collect::<Vec<_>>()
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        format!(
+            "rules: {}",
+            config
+                .enabled()
+                .map(|rule| rule.id())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Style::new().fg(colors.footer_border_color),
+    ));
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .border_style(Style::new().fg(colors.footer_border_color))
+        .title(format!(" lint: {name} | (L/Esc) close "));
+    let popup = Paragraph::new(lines)
+        .style(Style::new().fg(colors.row_fg).bg(colors.buffer_bg))
+        .wrap(Wrap { trim: false })
+        .block(block);
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
+
+/// Popup listing every column of the view with its visibility.
+pub fn render_column_chooser(f: &mut Frame, chooser: &ColumnChooser) {
+    let colors = &chooser.colors;
+    let area = centered_rect(50, 60, f.size());
+    let lines = chooser
+        .items
+        .iter()
+        .enumerate()
+        .map(|(item, (_, shown))| {
+            let mark = if *shown { "[x]" } else { "[ ]" };
+            let line = Line::from(format!(" {mark} {}", chooser.title(item)));
+            if item == chooser.selected {
+                line.style(
+                    Style::new()
+                        .add_modifier(Modifier::REVERSED)
+                        .fg(colors.selected_style_fg),
+                )
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>();
+    let title = match &chooser.error {
+        Some(error) => Span::styled(format!(" {error} "), Style::new().fg(Color::Red)),
+        None => Span::raw(format!(
+            " columns: {} | (Space) show | (J/K) move | (Esc) close ",
+            chooser.kind
+        )),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .border_style(Style::new().fg(colors.footer_border_color))
+        .title(title);
+    let popup = Paragraph::new(lines)
+        .style(Style::new().fg(colors.row_fg).bg(colors.buffer_bg))
+        .block(block);
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::data::generate_pod_recs;
+    use crate::tui::lint::LintConfig;
+    use crate::tui::pod_app::app::App;
+    use crate::tui::table_ui::{render_notice, Motion, TuiTableState};
+    use crate::tui::yaml_app::app::App as YamlApp;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use serde_json::json;
+
+    #[test]
+    fn test_apply_motion() {
+        let mut app = App::with_pods(generate_pod_recs(), None, &LintConfig::default()).table;
+        assert_eq!(20, app.items.len());
+        // A header, then 5 rows of 4 lines.
+        let mut terminal = Terminal::new(TestBackend::new(60, 21)).unwrap();
+        terminal.draw(|f| app.render(f, f.size())).unwrap();
+        let height = app.viewport;
+        assert_eq!(20, height);
+        assert_eq!(5, app.page_rows(height));
+        assert_eq!(1, app.page_rows(2));
+
+        let mut moves = |motion, count| {
+            app.apply_motion(motion, count, height);
+            app.state.selected()
+        };
+        assert_eq!(Some(5), moves(Motion::PageDown, None));
+        assert_eq!(Some(7), moves(Motion::HalfPageDown, None));
+        assert_eq!(Some(10), moves(Motion::Down, Some(3)));
+        assert_eq!(Some(19), moves(Motion::PageDown, Some(20)));
+        assert_eq!(Some(17), moves(Motion::HalfPageUp, None));
+        assert_eq!(Some(0), moves(Motion::Up, Some(100)));
+        assert_eq!(Some(19), moves(Motion::Last, None));
+        assert_eq!(Some(6), moves(Motion::Last, Some(7)));
+        assert_eq!(Some(3), moves(Motion::First, Some(4)));
+        assert_eq!(Some(0), moves(Motion::First, None));
+
+        let yaml = YamlApp::new("pod".to_string(), &json!({"a": 1}));
+        assert_eq!(20, yaml.page_rows(height));
+    }
+
+    #[test]
+    fn test_wraparound() {
+        let mut app = App::with_pods(generate_pod_recs(), None, &LintConfig::default()).table;
+        app.previous(false);
+        assert_eq!(Some(0), app.state.selected());
+        app.previous(true);
+        assert_eq!(Some(19), app.state.selected());
+        app.next(false);
+        assert_eq!(Some(19), app.state.selected());
+        app.next(true);
+        assert_eq!(Some(0), app.state.selected());
+    }
+
+    #[test]
+    fn test_render_notice() {
+        let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+        terminal
+            .draw(|f| render_notice(f, "search not supported here"))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let line = (0..40)
+            .map(|x| buffer.get(x, 5).symbol())
+            .collect::<String>();
+        assert_eq!(format!("{:12} search not supported here  ", ""), line);
+    }
+}
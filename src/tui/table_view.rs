@@ -0,0 +1,757 @@
+use crate::tui::columns::ColumnLayout;
+use crate::tui::data::Health;
+use crate::tui::filter::Filter;
+use crate::tui::marks::Marks;
+use crate::tui::search::{highlight, Search};
+use crate::tui::selector::Labels;
+use crate::tui::sort::{SortBy, SortKey};
+use crate::tui::style::{TableColors, ITEM_HEIGHT};
+use itertools::Itertools;
+use ratatui::layout::Flex;
+use ratatui::prelude::*;
+use ratatui::widgets::{Cell, HighlightSpacing, Row, Table, TableState};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Width of the " █ " highlight bar in front of every row.
+const HIGHLIGHT_WIDTH: u16 = 3;
+
+/// Width of the gutter after the highlight bar where marked rows get
+/// [`MARK_SYMBOL`], with the spacing after it.
+const GUTTER_WIDTH: u16 = 2;
+
+const MARK_SYMBOL: &str = "●";
+
+/// Blank columns after the text of each cell.
+const PADDING: u16 = 2;
+
+/// How a cell wider than its column is cut.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Truncate {
+    /// Cut at the column edge.
+    End,
+    /// Keep both ends, e.g. the hash suffix of a pod name.
+    Middle,
+    /// Cut and mark the cut with "…".
+    Ellipsis,
+}
+
+impl Truncate {
+    /// `text` cut to at most `width` columns.
+    pub fn apply(self, text: &str, width: usize) -> String {
+        if text.width() <= width {
+            return text.to_string();
+        }
+        match self {
+            Self::End => take_width(text.chars(), width),
+            Self::Ellipsis if width == 0 => String::new(),
+            Self::Ellipsis => take_width(text.chars(), width - 1) + "…",
+            Self::Middle if width == 0 => String::new(),
+            Self::Middle => {
+                let tail = (width - 1) / 2;
+                let head = take_width(text.chars(), width - 1 - tail);
+                let tail = take_width(text.chars().rev(), tail);
+                format!("{head}…{}", tail.chars().rev().collect::<String>())
+            }
+        }
+    }
+}
+
+fn take_width(chars: impl Iterator<Item = char>, width: usize) -> String {
+    let mut used = 0;
+    chars
+        .take_while(|c| {
+            used += c.width().unwrap_or(0);
+            used <= width
+        })
+        .collect()
+}
+
+/// How a column of a [`TableView`] is laid out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Column {
+    pub(crate) title: &'static str,
+    /// Narrowest the column gets when the terminal is too small; the title
+    /// width unless set.
+    pub(crate) min: u16,
+    pub(crate) max: u16,
+    pub(crate) align: Alignment,
+    pub(crate) truncate: Truncate,
+    pub(crate) sort: SortBy,
+    /// 0 is always shown; when columns do not fit, the highest number goes
+    /// first.
+    pub(crate) priority: u8,
+}
+
+impl Column {
+    /// A left aligned column cut with an ellipsis that is never hidden and
+    /// sorts in natural order.
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn new(title: &'static str) -> Self {
+        Self {
+            title,
+            min: title.len() as u16,
+            max: u16::MAX,
+            align: Alignment::Left,
+            truncate: Truncate::Ellipsis,
+            sort: SortBy::Natural,
+            priority: 0,
+        }
+    }
+
+    pub const fn min(self, min: u16) -> Self {
+        Self { min, ..self }
+    }
+
+    pub const fn max(self, max: u16) -> Self {
+        Self { max, ..self }
+    }
+
+    pub const fn align(self, align: Alignment) -> Self {
+        Self { align, ..self }
+    }
+
+    pub const fn truncate(self, truncate: Truncate) -> Self {
+        Self { truncate, ..self }
+    }
+
+    pub const fn sort(self, sort: SortBy) -> Self {
+        Self { sort, ..self }
+    }
+
+    pub const fn priority(self, priority: u8) -> Self {
+        Self { priority, ..self }
+    }
+
+    /// `text` cut to `width` and padded to it as the alignment asks.
+    pub fn fit(&self, text: &str, width: u16) -> String {
+        let width = usize::from(width);
+        let text = self.truncate.apply(text, width);
+        let gap = width.saturating_sub(text.width());
+        match self.align {
+            Alignment::Left => text,
+            Alignment::Center => {
+                format!("{}{text}{}", " ".repeat(gap / 2), " ".repeat(gap - gap / 2))
+            }
+            Alignment::Right => format!("{}{text}", " ".repeat(gap)),
+        }
+    }
+}
+
+/// Index of the column titled `title`; fails to compile in a const when
+/// there is none.
+pub const fn column_index(columns: &[Column], title: &str) -> usize {
+    let mut i = 0;
+    while i < columns.len() {
+        let (a, b) = (columns[i].title.as_bytes(), title.as_bytes());
+        if a.len() == b.len() {
+            let mut j = 0;
+            while j < a.len() && a[j] == b[j] {
+                j += 1;
+            }
+            if j == a.len() {
+                return i;
+            }
+        }
+        i += 1;
+    }
+    panic!("no such column");
+}
+
+/// The `shown` columns that fit in `available` cells as (column index,
+/// width). Each column is as wide as its widest cell within its min and max; when
+/// that is too wide the columns with the highest priority number are
+/// hidden, then the widest columns are narrowed down to their min.
+pub fn fit_columns(
+    columns: &[Column],
+    lens: &[u16],
+    shown: &[usize],
+    available: u16,
+) -> Vec<(usize, u16)> {
+    let total = |fitted: &[(usize, u16)]| {
+        fitted
+            .iter()
+            .map(|(_, width)| usize::from(width + PADDING) + 1)
+            .sum::<usize>()
+            .saturating_sub(1)
+    };
+    let mut fitted = shown
+        .iter()
+        .map(|&i| {
+            let column = &columns[i];
+            (i, lens[i].clamp(column.min.min(column.max), column.max))
+        })
+        .collect_vec();
+    while total(&fitted) > usize::from(available) {
+        let hidden = fitted
+            .iter()
+            .positions(|(i, _)| columns[*i].priority > 0)
+            .max_by_key(|&position| columns[fitted[position].0].priority);
+        match hidden {
+            Some(position) => fitted.remove(position),
+            None => break,
+        };
+    }
+    while total(&fitted) > usize::from(available) {
+        let narrowed = fitted
+            .iter_mut()
+            .filter(|(i, width)| *width > columns[*i].min)
+            .max_by_key(|(i, width)| *width - columns[*i].min);
+        match narrowed {
+            Some((_, width)) => *width -= 1,
+            None => break,
+        }
+    }
+    fitted
+}
+
+/// A resource that can be listed by [`TableView`]: its columns, the text of
+/// each column, and how the row is colored.
+pub trait TableRow {
+    /// Name the column layout of this kind is saved under, e.g. "pods".
+    const KIND: &'static str;
+
+    /// One column per cell.
+    const COLUMNS: &'static [Column];
+
+    /// Names of values a filter expression can use besides the columns,
+    /// e.g. "ready"; one per entry of [`TableRow::field_values`].
+    const FIELDS: &'static [&'static str] = &[];
+
+    /// Text of each column in header order; a cell may span two lines.
+    fn cells(&self) -> Vec<String>;
+
+    /// Identifies the row among its siblings, usually the object name.
+    fn key(&self) -> String;
+
+    fn field_values(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Labels a label selector matches, for kinds that have them.
+    fn labels(&self) -> Option<&Labels> {
+        None
+    }
+
+    fn health(&self) -> Health {
+        Health::Healthy
+    }
+
+    /// Colors of the whole row; by default those of its health, striped.
+    fn row_style(&self, colors: &TableColors, index: usize) -> Style {
+        colors.row_style(self.health(), index)
+    }
+
+    /// Style of one cell on top of the row style, e.g. to color usage.
+    fn cell_style(&self, _column: usize, _colors: &TableColors) -> Style {
+        Style::new()
+    }
+}
+
+/// The table every resource view renders: a header, one four line row per
+/// item colored by its health, and the " █ " highlight bar. Columns that do
+/// not fit are hidden or cut as their [`Column`] says.
+pub struct TableView<'a, T> {
+    items: &'a [T],
+    widths: &'a [u16],
+    colors: &'a TableColors,
+    order: &'a [SortKey],
+    search: Option<&'a Search>,
+    filter: Option<&'a Filter>,
+    marks: Option<&'a Marks>,
+    layout: Option<&'a ColumnLayout>,
+}
+
+impl<'a, T: TableRow> TableView<'a, T> {
+    /// `widths` are the widest cell of each column, without padding.
+    pub const fn new(items: &'a [T], widths: &'a [u16], colors: &'a TableColors) -> Self {
+        Self {
+            items,
+            widths,
+            colors,
+            order: &[],
+            search: None,
+            filter: None,
+            marks: None,
+            layout: None,
+        }
+    }
+
+    /// Mark the columns of `order` in the header: ▲/▼ on the primary key,
+    /// ▵/▿ on the others.
+    pub const fn sorted_by(mut self, order: &'a [SortKey]) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Highlight the matches of `search` inside the cells.
+    pub const fn searched(mut self, search: Option<&'a Search>) -> Self {
+        self.search = search;
+        self
+    }
+
+    /// Highlight the characters matched by `filter` inside the cells.
+    pub const fn filtered(mut self, filter: Option<&'a Filter>) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Put the gutter symbol on the rows in `marks`.
+    pub const fn marked(mut self, marks: &'a Marks) -> Self {
+        self.marks = Some(marks);
+        self
+    }
+
+    /// Order and hide the columns as `layout` says, instead of showing all
+    /// of them.
+    pub const fn laid_out(mut self, layout: &'a ColumnLayout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// `text` with the matches of the search, or else of the filter.
+    fn highlight(&self, text: String) -> Line<'static> {
+        let found = self
+            .search
+            .map(|search| search.find(&text))
+            .unwrap_or_default();
+        if !found.is_empty() {
+            return highlight(text, &found, self.colors.search_match_style());
+        }
+        match self.filter {
+            Some(filter) => {
+                let found = filter.find(&text);
+                highlight(text, &found, self.colors.filter_match_style())
+            }
+            None => Line::from(text),
+        }
+    }
+}
+
+/// Header title of column `i`, with its sort marker if it is in `order`.
+fn header_title(columns: &[Column], order: &[SortKey], i: usize) -> String {
+    let title = columns[i].title;
+    match order.iter().position(|key| key.column == i) {
+        Some(0) if order[0].descending => format!("{title} ▼"),
+        Some(0) => format!("{title} ▲"),
+        Some(p) if order[p].descending => format!("{title} ▿"),
+        Some(_) => format!("{title} ▵"),
+        None => title.to_string(),
+    }
+}
+
+/// The columns of `T` in `layout` as drawn `area_width` cells wide: (column
+/// index, x, width with padding, text width).
+#[allow(clippy::cast_possible_truncation)]
+fn column_rects<T: TableRow>(
+    widths: &[u16],
+    order: &[SortKey],
+    layout: &ColumnLayout,
+    area_width: u16,
+) -> Vec<(usize, u16, u16, u16)> {
+    // Room for the sort markers so they do not push out the titles.
+    let widths = widths
+        .iter()
+        .enumerate()
+        .map(|(i, &width)| width.max(header_title(T::COLUMNS, order, i).width() as u16))
+        .collect_vec();
+    let shown = layout.shown(T::COLUMNS);
+    let left = HIGHLIGHT_WIDTH + GUTTER_WIDTH;
+    let fitted = fit_columns(T::COLUMNS, &widths, &shown, area_width.saturating_sub(left));
+    let constraints = fitted
+        .iter()
+        .enumerate()
+        .map(|(position, (_, width))| match position {
+            0 => Constraint::Length(width + PADDING),
+            _ => Constraint::Min(width + PADDING),
+        });
+    let area = Rect::new(left, 0, area_width.saturating_sub(left), 1);
+    Layout::horizontal(constraints)
+        .flex(Flex::Start)
+        .spacing(1)
+        .split(area)
+        .iter()
+        .zip(fitted)
+        .map(|(rect, (i, width))| {
+            (
+                i,
+                rect.x,
+                rect.width,
+                width.min(rect.width.saturating_sub(PADDING)),
+            )
+        })
+        .collect()
+}
+
+/// The column of `T` under `x` on a table `area_width` cells wide, e.g. to
+/// sort by a clicked header.
+pub fn column_at<T: TableRow>(
+    widths: &[u16],
+    order: &[SortKey],
+    layout: &ColumnLayout,
+    area_width: u16,
+    x: u16,
+) -> Option<usize> {
+    column_rects::<T>(widths, order, layout, area_width)
+        .into_iter()
+        .find(|(_, left, width, _)| (*left..left + width).contains(&x))
+        .map(|(i, _, _, _)| i)
+}
+
+impl<T: TableRow> StatefulWidget for TableView<'_, T> {
+    type State = TableState;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut TableState) {
+        let colors = self.colors;
+        let header_style = Style::default().fg(colors.header_fg).bg(colors.header_bg);
+        let selected_style = Style::default()
+            .add_modifier(Modifier::REVERSED)
+            .fg(colors.selected_style_fg);
+
+        let default = ColumnLayout::default();
+        let layout = self.layout.unwrap_or(&default);
+        let rects = column_rects::<T>(self.widths, self.order, layout, area.width);
+
+        let header = std::iter::once(Cell::default())
+            .chain(rects.iter().map(|&(i, _, _, width)| {
+                Cell::from(T::COLUMNS[i].fit(&header_title(T::COLUMNS, self.order, i), width))
+            }))
+            .collect::<Row>()
+            .style(header_style)
+            .height(1);
+        let marked = self
+            .marks
+            .map(|marks| marks.marked(self.items, state.selected()))
+            .unwrap_or_default();
+        let rows = self.items.iter().enumerate().map(|(row, item)| {
+            let cells = item.cells();
+            let gutter = if marked.contains(&row) {
+                let mark = Line::from(MARK_SYMBOL);
+                Cell::from(Text::from(vec![Line::default(), mark.clone(), mark]))
+                    .style(colors.mark_style())
+            } else {
+                Cell::default()
+            };
+            std::iter::once(gutter)
+                .chain(rects.iter().map(|&(i, _, _, width)| {
+                    let lines = cells[i]
+                        .lines()
+                        .map(|line| self.highlight(T::COLUMNS[i].fit(line, width)));
+                    Cell::from(Text::from(
+                        std::iter::once(Line::default()).chain(lines).collect_vec(),
+                    ))
+                    .style(item.cell_style(i, colors))
+                }))
+                .collect::<Row>()
+                .style(item.row_style(colors, row))
+                .height(ITEM_HEIGHT as u16)
+        });
+        let widths = std::iter::once(Constraint::Length(GUTTER_WIDTH - 1)).chain(
+            rects
+                .iter()
+                .map(|&(_, _, width, _)| Constraint::Length(width)),
+        );
+        let bar = " █ ";
+        let t = Table::new(rows, widths)
+            .header(header)
+            .highlight_style(selected_style)
+            .highlight_symbol(Text::from(vec![
+                "".into(),
+                bar.into(),
+                bar.into(),
+                "".into(),
+            ]))
+            .bg(colors.buffer_bg)
+            .highlight_spacing(HighlightSpacing::Always);
+        StatefulWidget::render(t, area, buf, state);
+    }
+}
+
+/// A row for the tests of the table modules: a name, a status, when it was
+/// last seen and a ready count such as "3/4".
+#[cfg(test)]
+#[derive(Clone, Debug)]
+pub(crate) struct TestRow {
+    pub(crate) name: &'static str,
+    pub(crate) status: &'static str,
+    pub(crate) last_seen: &'static str,
+    pub(crate) pods: &'static str,
+    pub(crate) health: Health,
+}
+
+#[cfg(test)]
+impl TestRow {
+    /// A healthy row with empty other cells.
+    pub(crate) fn new(name: &'static str, status: &'static str) -> Self {
+        Self {
+            name,
+            status,
+            last_seen: "",
+            pods: "",
+            health: Health::Healthy,
+        }
+    }
+
+    pub(crate) fn seen(self, last_seen: &'static str) -> Self {
+        Self { last_seen, ..self }
+    }
+
+    pub(crate) fn pods(self, pods: &'static str) -> Self {
+        Self { pods, ..self }
+    }
+
+    pub(crate) fn health(self, health: Health) -> Self {
+        Self { health, ..self }
+    }
+}
+
+#[cfg(test)]
+impl TableRow for TestRow {
+    const KIND: &'static str = "test-rows";
+    const COLUMNS: &'static [Column] = &[
+        Column::new("Name"),
+        Column::new("Status").priority(1),
+        Column::new("Last Seen").sort(SortBy::Age).priority(2),
+        Column::new("Pods").sort(SortBy::Number).priority(3),
+    ];
+    const FIELDS: &'static [&'static str] = &["ready", "desired"];
+
+    fn cells(&self) -> Vec<String> {
+        [self.name, self.status, self.last_seen, self.pods]
+            .map(ToString::to_string)
+            .to_vec()
+    }
+
+    fn key(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn field_values(&self) -> Vec<String> {
+        self.pods.split('/').map(ToString::to_string).collect()
+    }
+
+    fn health(&self) -> Health {
+        self.health
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::columns::ColumnLayout;
+    use crate::tui::data::Health;
+    use crate::tui::marks::Marks;
+    use crate::tui::search::Search;
+    use crate::tui::sort::SortKey;
+    use crate::tui::style::{TableColors, PALETTES};
+    use crate::tui::table_view::{
+        column_at, column_index, fit_columns, Column, TableView, TestRow, Truncate,
+    };
+    use ratatui::backend::TestBackend;
+    use ratatui::layout::Alignment;
+    use ratatui::widgets::TableState;
+    use ratatui::Terminal;
+
+    #[test]
+    fn test_render_rows() {
+        let rows = [
+            TestRow::new("web", "Running"),
+            TestRow::new("api", "CrashLoopBackOff").health(Health::CrashLoop),
+        ];
+        let colors = TableColors::new(&PALETTES[0]);
+        let mut terminal = Terminal::new(TestBackend::new(30, 9)).unwrap();
+        let mut state = TableState::default().with_selected(Some(0));
+        terminal
+            .draw(|f| {
+                let table = TableView::new(&rows, &[4, 16, 0, 0], &colors);
+                f.render_stateful_widget(table, f.size(), &mut state);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let line = |y| {
+            (0..buffer.area.width)
+                .map(|x| buffer.get(x, y).symbol())
+                .collect::<String>()
+        };
+        assert_eq!("     Name   Status            ", line(0));
+        assert_eq!(" █   web    Running           ", line(2));
+        assert_eq!("     api    CrashLoopBackOff  ", line(6));
+        assert_eq!(colors.crash_loop_row_bg, buffer.get(5, 6).bg);
+    }
+
+    #[test]
+    fn test_render_marks() {
+        let rows = [
+            TestRow::new("web", "Running"),
+            TestRow::new("api", "Running"),
+        ];
+        let colors = TableColors::new(&PALETTES[0]);
+        let mut marks = Marks::default();
+        marks.toggle("api".to_string());
+        let mut terminal = Terminal::new(TestBackend::new(30, 9)).unwrap();
+        let mut state = TableState::default().with_selected(Some(0));
+        terminal
+            .draw(|f| {
+                let table = TableView::new(&rows, &[4, 7, 0, 0], &colors).marked(&marks);
+                f.render_stateful_widget(table, f.size(), &mut state);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let line = |y| {
+            (0..buffer.area.width)
+                .map(|x| buffer.get(x, y).symbol())
+                .collect::<String>()
+        };
+        assert_eq!(" █   web    Running           ", line(2));
+        assert_eq!("   ● api    Running           ", line(6));
+        assert_eq!(colors.mark_fg, buffer.get(3, 6).fg);
+    }
+
+    #[test]
+    fn test_render_search_matches() {
+        let rows = [TestRow::new("web", "Running")];
+        let colors = TableColors::new(&PALETTES[0]);
+        let search = Search::new("unn").unwrap();
+        let mut terminal = Terminal::new(TestBackend::new(30, 5)).unwrap();
+        terminal
+            .draw(|f| {
+                let table = TableView::new(&rows, &[4, 16, 0, 0], &colors).searched(Some(&search));
+                f.render_stateful_widget(table, f.size(), &mut TableState::default());
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let matched = (0..buffer.area.width)
+            .filter(|&x| buffer.get(x, 2).bg == colors.search_match_bg)
+            .map(|x| buffer.get(x, 2).symbol())
+            .collect::<String>();
+        assert_eq!("unn", matched);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let name = "replica008-yf6g3n85";
+        assert_eq!(name, Truncate::Middle.apply(name, 19));
+        assert_eq!("replica0", Truncate::End.apply(name, 8));
+        assert_eq!("replica…", Truncate::Ellipsis.apply(name, 8));
+        assert_eq!("repl…n85", Truncate::Middle.apply(name, 8));
+        assert_eq!("", Truncate::Ellipsis.apply(name, 0));
+    }
+
+    #[test]
+    fn test_column_index() {
+        const COLUMNS: [Column; 3] = [Column::new("C"), Column::new("CPU"), Column::new("CPUs")];
+        const CPU: usize = column_index(&COLUMNS, "CPU");
+        assert_eq!(1, CPU);
+        assert_eq!(2, column_index(&COLUMNS, "CPUs"));
+    }
+
+    #[test]
+    fn test_fit_columns() {
+        let columns = [
+            Column::new("Name"),
+            Column::new("Node").priority(2),
+            Column::new("Age").priority(1),
+        ];
+        // Each column takes its width, two cells of padding and one of spacing.
+        assert_eq!(
+            vec![(0, 10), (1, 20), (2, 3)],
+            fit_columns(&columns, &[10, 20, 3], &[0, 1, 2], 41)
+        );
+        assert_eq!(
+            vec![(0, 10), (2, 3)],
+            fit_columns(&columns, &[10, 20, 3], &[0, 1, 2], 40)
+        );
+        assert_eq!(
+            vec![(0, 10)],
+            fit_columns(&columns, &[10, 20, 3], &[0, 1, 2], 17)
+        );
+        assert_eq!(
+            vec![(0, 6)],
+            fit_columns(&columns, &[10, 20, 3], &[0, 1, 2], 8)
+        );
+        assert_eq!(
+            vec![(0, 4)],
+            fit_columns(&columns, &[10, 20, 3], &[0, 1, 2], 2)
+        );
+
+        assert_eq!(
+            vec![(2, 3), (0, 10)],
+            fit_columns(&columns, &[10, 20, 3], &[2, 0], 41)
+        );
+
+        let capped = [Column::new("Message").min(3).max(8)];
+        assert_eq!(vec![(0, 8)], fit_columns(&capped, &[40], &[0], 80));
+        assert_eq!(vec![(0, 3)], fit_columns(&capped, &[40], &[0], 0));
+    }
+
+    #[test]
+    fn test_column_fit() {
+        let count = Column::new("Count").align(Alignment::Right);
+        assert_eq!("   12", count.fit("12", 5));
+        assert_eq!(
+            " ab  ",
+            Column::new("x").align(Alignment::Center).fit("ab", 5)
+        );
+        assert_eq!("ab", Column::new("x").fit("ab", 5));
+    }
+
+    #[test]
+    fn test_render_hides_columns() {
+        let rows = [TestRow::new("web", "Running")];
+        let colors = TableColors::new(&PALETTES[0]);
+        let mut terminal = Terminal::new(TestBackend::new(12, 5)).unwrap();
+        terminal
+            .draw(|f| {
+                let table = TableView::new(&rows, &[4, 7, 0, 0], &colors);
+                f.render_stateful_widget(table, f.size(), &mut TableState::default());
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let line = (0..12)
+            .map(|x| buffer.get(x, 0).symbol())
+            .collect::<String>();
+        assert_eq!("     Name   ", line);
+    }
+
+    #[test]
+    fn test_sort_markers() {
+        let rows = [TestRow::new("web", "Running")];
+        let order = [
+            SortKey {
+                column: 1,
+                descending: true,
+            },
+            SortKey::ascending(0),
+        ];
+        let colors = TableColors::new(&PALETTES[0]);
+        let mut terminal = Terminal::new(TestBackend::new(30, 5)).unwrap();
+        terminal
+            .draw(|f| {
+                let table = TableView::new(&rows, &[4, 7, 0, 0], &colors).sorted_by(&order);
+                f.render_stateful_widget(table, f.size(), &mut TableState::default());
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let line = (0..30)
+            .map(|x| buffer.get(x, 0).symbol())
+            .collect::<String>();
+        assert_eq!("     Name ▵   Status ▼        ", line);
+        let layout = ColumnLayout::default();
+        assert_eq!(
+            None,
+            column_at::<TestRow>(&[4, 7, 0, 0], &order, &layout, 30, 3)
+        );
+        assert_eq!(
+            Some(0),
+            column_at::<TestRow>(&[4, 7, 0, 0], &order, &layout, 30, 5)
+        );
+        assert_eq!(
+            Some(1),
+            column_at::<TestRow>(&[4, 7, 0, 0], &order, &layout, 30, 15)
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use crate::tui::data::{
+    generate_pod_container_recs, generate_pod_recs, generate_rs_recs, Container, Health, Pod, Rs,
+};
+use fakeit::{generator, misc};
+use itertools::Itertools;
+
+/// An object in the owner reference tree.
+#[derive(Clone, Debug)]
+pub enum XrayObject {
+    Deployment(String),
+    ReplicaSet(Rs),
+    Pod(Pod),
+    Container(Container),
+}
+
+impl XrayObject {
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Deployment(_) => "Deployment",
+            Self::ReplicaSet(_) => "ReplicaSet",
+            Self::Pod(_) => "Pod",
+            Self::Container(_) => "Container",
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Deployment(name) => name,
+            Self::ReplicaSet(rs) => &rs.name,
+            Self::Pod(pod) => &pod.name,
+            Self::Container(container) => &container.name,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct XrayNode {
+    pub(crate) object: XrayObject,
+    pub(crate) status: String,
+    pub(crate) health: Health,
+    pub(crate) expanded: bool,
+    pub(crate) children: Vec<XrayNode>,
+}
+
+/// A visible line of the tree; `path` holds the child indexes from the root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XrayRow {
+    pub(crate) path: Vec<usize>,
+    pub(crate) cells: [String; 3],
+}
+
+impl AsRef<[String]> for XrayRow {
+    fn as_ref(&self) -> &[String] {
+        &self.cells
+    }
+}
+
+const fn severity(health: Health) -> u8 {
+    match health {
+        Health::Healthy => 0,
+        Health::Terminating => 1,
+        Health::Pending => 2,
+        Health::Degraded => 3,
+        Health::CrashLoop => 4,
+    }
+}
+
+/// The least healthy of `healths`, or healthy when there are none.
+fn worst(healths: impl IntoIterator<Item = Health>) -> Health {
+    healths
+        .into_iter()
+        .max_by_key(|health| severity(*health))
+        .unwrap_or(Health::Healthy)
+}
+
+impl XrayNode {
+    fn new(object: XrayObject, status: String, health: Health, children: Vec<Self>) -> Self {
+        Self {
+            object,
+            status,
+            health,
+            expanded: false,
+            children,
+        }
+    }
+
+    /// The node at `path` below `roots`.
+    pub fn at<'a>(roots: &'a [Self], path: &[usize]) -> Option<&'a Self> {
+        let (first, rest) = path.split_first()?;
+        rest.iter()
+            .try_fold(roots.get(*first)?, |node, i| node.children.get(*i))
+    }
+
+    pub fn at_mut<'a>(roots: &'a mut [Self], path: &[usize]) -> Option<&'a mut Self> {
+        let (first, rest) = path.split_first()?;
+        rest.iter()
+            .try_fold(roots.get_mut(*first)?, |node, i| node.children.get_mut(*i))
+    }
+
+    /// The visible rows of `roots`: a node's children only show while it is
+    /// expanded. Each row carries its tree guides, expand marker and icon.
+    pub fn flatten(roots: &[Self]) -> Vec<XrayRow> {
+        fn walk(nodes: &[XrayNode], path: &mut Vec<usize>, guides: &str, rows: &mut Vec<XrayRow>) {
+            for (i, node) in nodes.iter().enumerate() {
+                let last = i + 1 == nodes.len();
+                let branch = match (path.is_empty(), last) {
+                    (true, _) => "",
+                    (false, true) => "└─ ",
+                    (false, false) => "├─ ",
+                };
+                let marker = match (node.children.is_empty(), node.expanded) {
+                    (true, _) => ' ',
+                    (false, true) => '▾',
+                    (false, false) => '▸',
+                };
+                path.push(i);
+                rows.push(XrayRow {
+                    path: path.clone(),
+                    cells: [
+                        format!(
+                            "{guides}{branch}{marker} {} {}",
+                            node.health.icon(),
+                            node.object.name()
+                        ),
+                        node.object.kind().to_string(),
+                        node.status.clone(),
+                    ],
+                });
+                if node.expanded {
+                    let guides = match (path.len() == 1, last) {
+                        (true, _) => String::new(),
+                        (false, true) => format!("{guides}   "),
+                        (false, false) => format!("{guides}│  "),
+                    };
+                    walk(&node.children, path, &guides, rows);
+                }
+                path.pop();
+            }
+        }
+
+        let mut rows = Vec::new();
+        walk(roots, &mut Vec::new(), "", &mut rows);
+        rows
+    }
+
+    pub fn replicasets(&self) -> Vec<Rs> {
+        self.children
+            .iter()
+            .filter_map(|child| match &child.object {
+                XrayObject::ReplicaSet(rs) => Some(rs.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn pods(&self) -> Vec<Pod> {
+        self.children
+            .iter()
+            .filter_map(|child| match &child.object {
+                XrayObject::Pod(pod) => Some(pod.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn containers(&self) -> Vec<Container> {
+        self.children
+            .iter()
+            .filter_map(|child| match &child.object {
+                XrayObject::Container(container) => Some(container.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn set_expanded_all(&mut self, expanded: bool) {
+        self.expanded = expanded && !self.children.is_empty();
+        for child in &mut self.children {
+            child.set_expanded_all(expanded);
+        }
+    }
+}
+
+fn container_node(container: Container, pod: &Pod, ready: bool) -> XrayNode {
+    let (status, health) = match (ready, pod.health()) {
+        (true, _) => ("Ready".to_string(), Health::Healthy),
+        (false, Health::Healthy | Health::Degraded) => ("NotReady".to_string(), Health::Degraded),
+        (false, health) => (pod.status.clone(), health),
+    };
+    XrayNode::new(XrayObject::Container(container), status, health, Vec::new())
+}
+
+fn pod_node(pod: Pod) -> XrayNode {
+    let ready = pod
+        .containers
+        .split_once('/')
+        .and_then(|(ready, _)| ready.parse::<usize>().ok())
+        .unwrap_or(0);
+    let containers = generate_pod_container_recs(&pod)
+        .into_iter()
+        .enumerate()
+        .map(|(i, container)| container_node(container, &pod, i < ready))
+        .collect();
+    XrayNode::new(
+        XrayObject::Pod(pod.clone()),
+        pod.status.clone(),
+        pod.health(),
+        containers,
+    )
+}
+
+/// The pods of `rs`: as many running as it has ready, the rest in trouble.
+fn replicaset_node(rs: Rs) -> XrayNode {
+    let (ready, desired) = rs
+        .pods
+        .split_once('/')
+        .and_then(|(ready, desired)| Some((ready.parse().ok()?, desired.parse().ok()?)))
+        .unwrap_or((0, 0));
+    let pods = generate_pod_recs()
+        .into_iter()
+        .take(desired)
+        .enumerate()
+        .map(|(i, pod)| {
+            let status = if i < ready {
+                "Running".to_string()
+            } else {
+                misc::random_data(&["Pending", "CrashLoopBackOff"]).to_string()
+            };
+            Pod {
+                name: generator::generate(format!("{}-?????", rs.name)),
+                containers: if i < ready { "2/2" } else { "1/2" }.to_string(),
+                status,
+                ..pod
+            }
+        })
+        .sorted_by(|a, b| a.name.cmp(&b.name))
+        .map(pod_node)
+        .collect_vec();
+    XrayNode::new(
+        XrayObject::ReplicaSet(rs.clone()),
+        format!("{} pods", rs.pods),
+        rs.health(),
+        pods,
+    )
+}
+
+/// Deployments owning the current ReplicaSet plus one scaled down by an
+/// earlier rollout, each down to its containers.
+pub fn generate_xray_tree() -> Vec<XrayNode> {
+    generate_rs_recs()
+        .into_iter()
+        .take(8)
+        .map(|rs| {
+            let deployment = rs.name.split('-').next().unwrap_or_default().to_string();
+            let previous = Rs {
+                name: generator::generate(format!("{deployment}-??#?#?##")),
+                pods: "0/0".to_string(),
+                ..rs.clone()
+            };
+            let replicasets = vec![replicaset_node(rs.clone()), replicaset_node(previous)];
+            let health = worst(replicasets.iter().map(|node| node.health));
+            XrayNode::new(
+                XrayObject::Deployment(deployment),
+                format!("{} ready", rs.pods),
+                health,
+                replicasets,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::data::{Container, Health};
+    use crate::tui::lint::ContainerSpec;
+    use crate::tui::metrics::{Quantity, ResourceMetrics};
+    use crate::tui::xray::{worst, XrayNode, XrayObject};
+
+    fn leaf(name: &str) -> XrayNode {
+        let container = Container {
+            name: name.to_string(),
+            description: "Pod Container".to_string(),
+            metrics: ResourceMetrics::new(Quantity::new(250, 500), Quantity::new(128, 256)),
+            spec: ContainerSpec::default(),
+            findings: Vec::new(),
+        };
+        XrayNode::new(
+            XrayObject::Container(container),
+            "Ready".to_string(),
+            Health::Healthy,
+            Vec::new(),
+        )
+    }
+
+    fn tree() -> Vec<XrayNode> {
+        let parent = |name: &str, children| {
+            XrayNode::new(
+                XrayObject::Deployment(name.to_string()),
+                String::new(),
+                Health::Healthy,
+                children,
+            )
+        };
+        vec![
+            parent(
+                "web",
+                vec![parent("web-1", vec![leaf("a"), leaf("b")]), leaf("c")],
+            ),
+            parent("api", vec![leaf("d")]),
+        ]
+    }
+
+    #[test]
+    fn test_flatten_follows_expansion() {
+        let mut roots = tree();
+        let names = |roots: &[XrayNode]| {
+            XrayNode::flatten(roots)
+                .into_iter()
+                .map(|row| row.cells[0].clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec!["▸ ✔ web", "▸ ✔ api"], names(&roots));
+
+        roots[0].set_expanded_all(true);
+        assert_eq!(
+            vec![
+                "▾ ✔ web",
+                "├─ ▾ ✔ web-1",
+                "│  ├─   ✔ a",
+                "│  └─   ✔ b",
+                "└─   ✔ c",
+                "▸ ✔ api",
+            ],
+            names(&roots)
+        );
+
+        let rows = XrayNode::flatten(&roots);
+        assert_eq!(vec![0, 0, 1], rows[3].path);
+        assert_eq!(
+            "b",
+            XrayNode::at(&roots, &rows[3].path).unwrap().object.name()
+        );
+
+        XrayNode::at_mut(&mut roots, &[0, 0]).unwrap().expanded = false;
+        assert_eq!(4, XrayNode::flatten(&roots).len());
+        assert!(XrayNode::at(&roots, &[2]).is_none());
+    }
+
+    #[test]
+    fn test_worst_health() {
+        assert_eq!(Health::Healthy, worst([]));
+        assert_eq!(
+            Health::CrashLoop,
+            worst([Health::Pending, Health::CrashLoop, Health::Degraded])
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::tui::data::columns_len_calculator;
+use crate::tui::style::{TableColors, ITEM_HEIGHT, PALETTES};
+use crate::tui::table_ui::TuiTableState;
+use crate::tui::xray::{generate_xray_tree, XrayNode, XrayRow};
+use ratatui::widgets::{ScrollbarState, TableState};
+
+pub const HEADER: [&str; 3] = ["Name", "Kind", "Status"];
+
+/// Owner reference tree from Deployments down to containers; `items` are
+/// the rows currently visible in `roots`.
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) state: TableState,
+    pub(crate) roots: Vec<XrayNode>,
+    pub(crate) items: Vec<XrayRow>,
+    pub(crate) longest_item_lens: Vec<u16>,
+    pub(crate) scroll_state: ScrollbarState,
+    pub(crate) colors: TableColors,
+    /// Lines the rows had below the header when last drawn.
+    pub(crate) viewport: u16,
+    color_index: usize,
+}
+
+impl TuiTableState for App {
+    type Item = XrayRow;
+    const ROW_HEIGHT: usize = 1;
+
+    fn get_items(&self) -> &[Self::Item] {
+        &self.items
+    }
+
+    fn get_state(&mut self) -> &mut TableState {
+        &mut self.state
+    }
+
+    fn get_scroll_state(&self) -> &ScrollbarState {
+        &self.scroll_state
+    }
+
+    fn set_scroll_state(&mut self, scroll_state: ScrollbarState) {
+        self.scroll_state = scroll_state;
+    }
+
+    fn get_table_colors(&self) -> &TableColors {
+        &self.colors
+    }
+
+    fn set_table_colors(&mut self, colors: TableColors) {
+        self.colors = colors;
+    }
+
+    fn get_color_index(&self) -> usize {
+        self.color_index
+    }
+
+    fn set_color_index(&mut self, color_index: usize) {
+        self.color_index = color_index;
+    }
+}
+
+impl App {
+    pub fn new() -> Self {
+        let mut app = Self {
+            state: TableState::default(),
+            roots: generate_xray_tree(),
+            items: Vec::new(),
+            longest_item_lens: Vec::new(),
+            scroll_state: ScrollbarState::default(),
+            colors: TableColors::new(&PALETTES[0]),
+            viewport: 0,
+            color_index: 3,
+        };
+        app.refresh();
+        app.reset_selection();
+        app
+    }
+
+    pub fn selected_node(&self) -> Option<&XrayNode> {
+        let row = self.items.get(self.state.selected()?)?;
+        XrayNode::at(&self.roots, &row.path)
+    }
+
+    pub fn selected_path(&self) -> Option<Vec<usize>> {
+        let row = self.items.get(self.state.selected()?)?;
+        Some(row.path.clone())
+    }
+
+    /// Rebuild the visible rows after an expand or collapse, keeping the
+    /// selection on `path`.
+    fn select_path(&mut self, path: &[usize]) {
+        self.refresh();
+        if let Some(i) = self.items.iter().position(|row| row.path == path) {
+            self.state.select(Some(i));
+            self.scroll_state = self.scroll_state.position(i * ITEM_HEIGHT);
+        }
+    }
+
+    fn refresh(&mut self) {
+        self.items = XrayNode::flatten(&self.roots);
+        self.longest_item_lens = columns_len_calculator(
+            &std::iter::once(HEADER.map(ToString::to_string))
+                .chain(self.items.iter().map(|row| row.cells.clone()))
+                .collect::<Vec<_>>(),
+        );
+        self.scroll_state = self
+            .scroll_state
+            .content_length(self.items.len().saturating_sub(1) * ITEM_HEIGHT);
+    }
+
+    fn set_expanded(&mut self, path: &[usize], expanded: bool) {
+        if let Some(node) = XrayNode::at_mut(&mut self.roots, path) {
+            node.expanded = expanded && !node.children.is_empty();
+        }
+        self.select_path(path);
+    }
+
+    pub fn expand(&mut self) {
+        if let Some(path) = self.selected_path() {
+            self.set_expanded(&path, true);
+        }
+    }
+
+    /// Collapse the selected node, or move up to its parent when there is
+    /// nothing to collapse.
+    pub fn collapse(&mut self) {
+        let Some(mut path) = self.selected_path() else {
+            return;
+        };
+        if self.selected_node().is_some_and(|node| node.expanded) {
+            self.set_expanded(&path, false);
+        } else if path.len() > 1 {
+            path.pop();
+            self.select_path(&path);
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        if let Some(path) = self.selected_path() {
+            let expanded = self.selected_node().is_some_and(|node| node.expanded);
+            self.set_expanded(&path, !expanded);
+        }
+    }
+
+    /// Expand or collapse the whole tree under the selected node.
+    pub fn set_expanded_all(&mut self, expanded: bool) {
+        if let Some(path) = self.selected_path() {
+            if let Some(node) = XrayNode::at_mut(&mut self.roots, &path) {
+                node.set_expanded_all(expanded);
+            }
+            self.select_path(&path);
+        }
+    }
+}
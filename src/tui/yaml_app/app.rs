@@ -0,0 +1,76 @@
+use crate::tui::discovery::to_yaml;
+use crate::tui::style::{TableColors, PALETTES};
+use crate::tui::table_ui::TuiTableState;
+use ratatui::widgets::{ScrollbarState, TableState};
+use serde_json::Value;
+
+/// A read only YAML document, one row per line so the usual movement keys
+/// scroll through it.
+#[derive(Clone, Debug)]
+pub struct App {
+    pub(crate) state: TableState,
+    pub(crate) items: Vec<String>,
+    pub(crate) title: String,
+    pub(crate) scroll_state: ScrollbarState,
+    pub(crate) colors: TableColors,
+    /// Lines the rows had below the header when last drawn.
+    pub(crate) viewport: u16,
+    color_index: usize,
+}
+
+impl TuiTableState for App {
+    type Item = String;
+    const ROW_HEIGHT: usize = 1;
+
+    fn get_items(&self) -> &[Self::Item] {
+        &self.items
+    }
+
+    fn get_state(&mut self) -> &mut TableState {
+        &mut self.state
+    }
+
+    fn get_scroll_state(&self) -> &ScrollbarState {
+        &self.scroll_state
+    }
+
+    fn set_scroll_state(&mut self, scroll_state: ScrollbarState) {
+        self.scroll_state = scroll_state;
+    }
+
+    fn get_table_colors(&self) -> &TableColors {
+        &self.colors
+    }
+
+    fn set_table_colors(&mut self, colors: TableColors) {
+        self.colors = colors;
+    }
+
+    fn get_color_index(&self) -> usize {
+        self.color_index
+    }
+
+    fn set_color_index(&mut self, color_index: usize) {
+        self.color_index = color_index;
+    }
+}
+
+impl App {
+    pub fn new(title: String, object: &Value) -> Self {
+        let lines = to_yaml(object)
+            .lines()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let mut app = Self {
+            state: TableState::default(),
+            scroll_state: ScrollbarState::default(),
+            colors: TableColors::new(&PALETTES[0]),
+            viewport: 0,
+            color_index: 2,
+            title,
+            items: lines,
+        };
+        app.reset_selection();
+        app
+    }
+}
@@ -0,0 +1,57 @@
+use crate::tui::data::Health;
+use crate::tui::prompt::Prompt;
+use crate::tui::table_ui::{render_footer, TuiTableState};
+use crate::tui::yaml_app::app::App;
+use ratatui::{
+    prelude::*,
+    widgets::{Cell, HighlightSpacing, Row, Scrollbar, ScrollbarOrientation, Table},
+};
+
+pub fn ui(f: &mut Frame, app: &mut App, prompt: Option<&Prompt>) {
+    let rects = Layout::vertical([Constraint::Min(5), Constraint::Length(3)]).split(f.size());
+
+    app.set_colors();
+
+    render_table(f, app, rects[0]);
+
+    render_scrollbar(f, app, rects[0]);
+
+    render_footer(
+        f,
+        app.get_table_colors(),
+        rects[1],
+        prompt,
+        Some(app.title.clone()),
+    );
+}
+
+fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let selected_style = Style::default()
+        .add_modifier(Modifier::REVERSED)
+        .fg(app.colors.selected_style_fg);
+
+    let rows = app.items.iter().map(|line| {
+        Row::new([Cell::from(line.as_str())]).style(app.colors.row_style(Health::Healthy, 0))
+    });
+    let t = Table::new(rows, [Constraint::Percentage(100)])
+        .highlight_style(selected_style)
+        .highlight_symbol(" █ ")
+        .bg(app.colors.buffer_bg)
+        .highlight_spacing(HighlightSpacing::Always);
+    f.render_stateful_widget(t, area, &mut app.state);
+    app.viewport = area.height;
+}
+
+fn render_scrollbar(f: &mut Frame, app: &mut App, area: Rect) {
+    f.render_stateful_widget(
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None),
+        area.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        }),
+        &mut app.scroll_state,
+    );
+}